@@ -70,7 +70,14 @@ pub(crate) unsafe extern "C" fn get_clipboard_text(
 
         let ctx = &mut *(user_data as *mut ClipboardContext);
         match ctx.backend.get() {
-            Some(text) => {
+            Some(mut text) => {
+                // a C string ends at the first nul, so drop everything after it rather than
+                // failing the conversion
+                if let Some(nul) = text.find('\0') {
+                    text.truncate(nul);
+                }
+                // Dear ImGui borrows the returned pointer until the next call, so keep the
+                // string alive in the context
                 ctx.last_value = CString::new(text).unwrap();
                 ctx.last_value.as_ptr()
             }
@@ -91,8 +98,8 @@ pub(crate) unsafe extern "C" fn set_clipboard_text(
         let user_data = unsafe { (*sys::igGetPlatformIO_Nil()).Platform_ClipboardUserData };
 
         let ctx = &mut *(user_data as *mut ClipboardContext);
-        let text = CStr::from_ptr(text).to_owned();
-        ctx.backend.set(text.to_str().unwrap());
+        let text = CStr::from_ptr(text).to_string_lossy();
+        ctx.backend.set(&text);
     });
     result.unwrap_or_else(|_| {
         eprintln!("Clipboard setter panicked");
@@ -159,3 +166,33 @@ impl Ui {
         }
     }
 }
+
+#[test]
+fn test_clipboard_trampolines() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct SharedClipboard(Rc<RefCell<Option<String>>>);
+    impl ClipboardBackend for SharedClipboard {
+        fn get(&mut self) -> Option<String> {
+            self.0.borrow().clone()
+        }
+        fn set(&mut self, value: &str) {
+            *self.0.borrow_mut() = Some(value.to_owned());
+        }
+    }
+
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let contents = Rc::new(RefCell::new(None));
+    ctx.set_clipboard_backend(SharedClipboard(contents.clone()));
+    unsafe {
+        assert!(get_clipboard_text(ptr::null_mut()).is_null());
+
+        set_clipboard_text(ptr::null_mut(), c"caf\xc3\xa9 \xff".as_ptr());
+        assert_eq!(contents.borrow().as_deref(), Some("caf\u{e9} \u{fffd}"));
+
+        *contents.borrow_mut() = Some("before\0after".to_owned());
+        let text = CStr::from_ptr(get_clipboard_text(ptr::null_mut()));
+        assert_eq!(text.to_str(), Ok("before"));
+    }
+}
@@ -9,9 +9,9 @@ use crate::clipboard::{ClipboardBackend, ClipboardContext};
 use crate::fonts::atlas::{FontAtlas, FontId, SharedFontAtlas};
 use crate::ime::{ImeDataBackend, ImeDataContext};
 use crate::io::Io;
-use crate::style::Style;
-use crate::{sys, DrawData};
-use crate::{MouseCursor, Ui};
+use crate::style::{nav_colors_from_accent, Style, StyleColor, StyleExtras};
+use crate::{sys, DrawData, Textures};
+use crate::{Id, MouseCursor, Ui};
 
 #[cfg(feature = "docking")]
 use crate::docking_utils;
@@ -64,6 +64,9 @@ pub struct Context {
     // imgui a mutable pointer to it.
     clipboard_ctx: Box<UnsafeCell<ClipboardContext>>,
     ime_data_ctx: Box<UnsafeCell<ImeDataContext>>,
+    // color and style var stack depths recorded at `new_frame`, if the
+    // style stack guard is enabled.
+    style_stack_guard: Option<(i32, i32)>,
 
     ui: Ui,
 }
@@ -81,6 +84,13 @@ fn no_current_context() -> bool {
     let ctx = unsafe { sys::igGetCurrentContext() };
     ctx.is_null()
 }
+/// Returns the current depths of the style color and style var stacks.
+fn style_stack_depths() -> (i32, i32) {
+    unsafe {
+        let ctx = &*sys::igGetCurrentContext();
+        (ctx.ColorStack.Size, ctx.StyleVarStack.Size)
+    }
+}
 
 impl Context {
     /// Creates a new active imgui-rs context.
@@ -261,8 +271,11 @@ impl Context {
             renderer_name: None,
             clipboard_ctx: Box::new(ClipboardContext::dummy().into()),
             ime_data_ctx: Box::new(ImeDataContext::dummy().into()),
+            style_stack_guard: None,
             ui: Ui {
                 buffer: UnsafeCell::new(crate::string::UiBuffer::new(1024)),
+                viewport_styles: Default::default(),
+                style_extras: Default::default(),
             },
         }
     }
@@ -288,6 +301,36 @@ impl Drop for Context {
     }
 }
 
+/// A snapshot of a context's appearance, taken with [`Context::editor_snapshot`], for undo in
+/// style editors.
+///
+/// It holds the style and [`StyleExtras`], and optionally a copy of a renderer's textures
+/// added with [`with_textures`](Self::with_textures).
+#[derive(Clone, Debug)]
+pub struct EditorSnapshot<T = ()> {
+    style: Style,
+    style_extras: StyleExtras,
+    textures: Option<Textures<T>>,
+}
+
+impl EditorSnapshot {
+    /// Adds a copy of `textures` to the snapshot.
+    pub fn with_textures<T: Clone>(self, textures: &Textures<T>) -> EditorSnapshot<T> {
+        EditorSnapshot {
+            style: self.style,
+            style_extras: self.style_extras,
+            textures: Some(textures.clone()),
+        }
+    }
+}
+
+impl<T> EditorSnapshot<T> {
+    /// Returns the style captured by this snapshot.
+    pub fn style(&self) -> &Style {
+        &self.style
+    }
+}
+
 /// A suspended imgui-rs context.
 ///
 /// A suspended context retains its state, but is not usable without activating it first.
@@ -351,8 +394,11 @@ impl SuspendedContext {
             renderer_name: None,
             clipboard_ctx: Box::new(ClipboardContext::dummy().into()),
             ime_data_ctx: Box::new(ImeDataContext::dummy().into()),
+            style_stack_guard: None,
             ui: Ui {
                 buffer: UnsafeCell::new(crate::string::UiBuffer::new(1024)),
+                viewport_styles: Default::default(),
+                style_extras: Default::default(),
             },
         };
         if ctx.is_current_context() {
@@ -446,6 +492,86 @@ fn test_shared_font_atlas() {
     let _borrow = ctx.fonts();
 }
 
+#[test]
+fn test_style_stack_guard() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.enable_style_stack_guard(true);
+
+    let ui = ctx.new_frame();
+    let _token = ui.push_style_color(crate::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]);
+    drop(_token);
+    ctx.render();
+
+    let ui = ctx.new_frame();
+    std::mem::forget(ui.push_style_color(crate::StyleColor::Text, [1.0, 0.0, 0.0, 1.0]));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        ctx.render();
+    }));
+    assert!(result.is_err());
+
+    // rebalance the stack so dropping the context doesn't trip Dear ImGui's own assertion
+    unsafe { sys::igPopStyleColor(1) };
+}
+
+#[test]
+fn test_link_nav_to_accent() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.style_extras_mut().link_nav_to_accent = true;
+    let raw_color = |c: StyleColor| {
+        let v = unsafe { (*sys::igGetStyle()).Colors[c as usize] };
+        [v.x, v.y, v.z, v.w]
+    };
+    unsafe {
+        (*sys::igGetStyle()).Colors[StyleColor::HeaderActive as usize] =
+            [0.9, 0.2, 0.1, 0.5].into();
+    }
+    let _ = ctx.new_frame();
+    let _ = ctx.render();
+    assert_eq!(raw_color(StyleColor::NavCursor), [0.9, 0.2, 0.1, 1.0]);
+    assert_eq!(
+        raw_color(StyleColor::NavWindowingHighlight),
+        [0.9, 0.2, 0.1, 0.7]
+    );
+}
+
+#[test]
+fn test_editor_snapshot() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let mut textures = Textures::new();
+    let kept = textures.insert("kept");
+
+    ctx.style_mut().alpha = 0.5;
+    let snapshot = ctx.editor_snapshot().with_textures(&textures);
+
+    ctx.style_mut().alpha = 0.25;
+    ctx.style_extras_mut().link_nav_to_accent = true;
+    let added = textures.insert("added");
+
+    textures = ctx.restore_editor_snapshot(snapshot).unwrap();
+    assert_eq!(ctx.style().alpha, 0.5);
+    assert!(!ctx.style_extras().link_nav_to_accent);
+    assert_eq!(textures.get(kept), Some(&"kept"));
+    assert_eq!(textures.get(added), None);
+}
+
+#[test]
+fn test_viewport_style() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let main = Id(1);
+    let tool = Id(2);
+    let compact = Style {
+        window_padding: [2.0, 2.0],
+        ..Style::default()
+    };
+    ctx.set_viewport_style(tool, compact);
+
+    assert_eq!(ctx.viewport_style(tool), &compact);
+    assert!(std::ptr::eq(ctx.viewport_style(main), ctx.style()));
+
+    assert_eq!(ctx.remove_viewport_style(tool), Some(compact));
+    assert!(std::ptr::eq(ctx.viewport_style(tool), ctx.style()));
+}
+
 #[test]
 fn test_ini_load_save() {
     let (_guard, mut ctx) = crate::test::test_ctx();
@@ -527,6 +653,56 @@ impl Context {
             &mut *(sys::igGetStyle() as *mut Style)
         }
     }
+    /// Registers a style override for the viewport with the given id.
+    ///
+    /// Windows submitted with [`Window::viewport`](crate::Window::viewport) push the values of
+    /// the override which differ from the global style until they end. Other code can apply
+    /// it with [`Ui::push_viewport_style`](crate::Ui::push_viewport_style).
+    pub fn set_viewport_style(&mut self, viewport: Id, style: Style) {
+        self.ui.viewport_styles.insert(viewport, style);
+    }
+    /// Removes the style override for the viewport with the given id, returning it if it existed.
+    pub fn remove_viewport_style(&mut self, viewport: Id) -> Option<Style> {
+        self.ui.viewport_styles.remove(&viewport)
+    }
+    /// Returns the style used for the viewport with the given id: its override if one was
+    /// registered with [`set_viewport_style`](Self::set_viewport_style), or the global style.
+    pub fn viewport_style(&self, viewport: Id) -> &Style {
+        self.ui
+            .viewport_styles
+            .get(&viewport)
+            .unwrap_or_else(|| self.style())
+    }
+    /// Returns a reference to the imgui-rs specific style settings.
+    pub fn style_extras(&self) -> &StyleExtras {
+        &self.ui.style_extras
+    }
+    /// Returns a mutable reference to the imgui-rs specific style settings.
+    pub fn style_extras_mut(&mut self) -> &mut StyleExtras {
+        &mut self.ui.style_extras
+    }
+    /// Captures the current style and [`StyleExtras`] as a single undo step.
+    ///
+    /// Textures are owned by the renderer, so add them with
+    /// [`EditorSnapshot::with_textures`] to include them in the snapshot.
+    pub fn editor_snapshot(&self) -> EditorSnapshot {
+        EditorSnapshot {
+            style: *self.style(),
+            style_extras: self.ui.style_extras,
+            textures: None,
+        }
+    }
+    /// Restores the style and [`StyleExtras`] captured by [`editor_snapshot`](Self::editor_snapshot).
+    ///
+    /// Returns the snapshot's textures, if any, for the caller to hand back to its renderer.
+    pub fn restore_editor_snapshot<T>(
+        &mut self,
+        snapshot: EditorSnapshot<T>,
+    ) -> Option<Textures<T>> {
+        *self.style_mut() = snapshot.style;
+        self.ui.style_extras = snapshot.style_extras;
+        snapshot.textures
+    }
     /// Returns a mutable reference to the font atlas.
     pub fn fonts(&mut self) -> &mut FontAtlas {
         // we take this with an `&mut Self` here, which means
@@ -555,10 +731,22 @@ impl Context {
         if !default_font.is_null() && self.fonts().get_font(FontId(default_font)).is_none() {
             self.io_mut().font_default = ptr::null_mut();
         }
+        if self.ui.style_extras.link_nav_to_accent {
+            // goes through the raw style so only the colors are touched
+            let colors = unsafe { &mut (*sys::igGetStyle()).Colors };
+            let accent = colors[StyleColor::HeaderActive as usize];
+            let [cursor, windowing] =
+                nav_colors_from_accent([accent.x, accent.y, accent.z, accent.w]);
+            colors[StyleColor::NavCursor as usize] = cursor.into();
+            colors[StyleColor::NavWindowingHighlight as usize] = windowing.into();
+        }
         // TODO: precondition checks
         unsafe {
             sys::igNewFrame();
         }
+        if self.style_stack_guard.is_some() {
+            self.style_stack_guard = Some(style_stack_depths());
+        }
 
         &mut self.ui
     }
@@ -570,12 +758,39 @@ impl Context {
     /// [`new_frame`]: Self::new_frame
     #[doc(alias = "Render", alias = "GetDrawData")]
     pub fn render(&mut self) -> &DrawData {
+        if let Some((colors, vars)) = self.style_stack_guard {
+            let (end_colors, end_vars) = style_stack_depths();
+            assert!(
+                colors == end_colors && vars == end_vars,
+                "style stack imbalance at end of frame: {} color(s) and {} style var(s) left pushed",
+                end_colors - colors,
+                end_vars - vars,
+            );
+        }
         unsafe {
             sys::igRender();
             &*(sys::igGetDrawData() as *mut DrawData)
         }
     }
 
+    /// Enables or disables the style stack guard, which is off by default.
+    ///
+    /// When enabled, the depths of the style color and style var stacks are recorded in
+    /// [`new_frame`] and checked again in [`render`], which panics if anything pushed during the
+    /// frame was not popped. This catches leaked [`ColorStackToken`](crate::ColorStackToken)s
+    /// and [`StyleStackToken`](crate::StyleStackToken)s anywhere in a frame, with a clearer
+    /// message than Dear ImGui's own assertion.
+    ///
+    /// [`new_frame`]: Self::new_frame
+    /// [`render`]: Self::render
+    pub fn enable_style_stack_guard(&mut self, enabled: bool) {
+        self.style_stack_guard = if enabled {
+            Some(style_stack_depths())
+        } else {
+            None
+        };
+    }
+
     /// Returns the currently desired mouse cursor type.
     ///
     /// This was set *last frame* by the [Ui] object, and will be reset when
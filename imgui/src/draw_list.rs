@@ -302,6 +302,38 @@ impl<'ui> DrawListMut<'ui> {
         }
     }
 
+    // the number of vertices in the draw list, e.g. to find the vertices added by a primitive
+    pub(crate) fn vtx_count(&self) -> usize {
+        unsafe { (*self.draw_list).VtxBuffer.Size as usize }
+    }
+
+    // recolors the vertices from index `start` on with a vertical gradient from `top` at `y0`
+    // to `bottom` at `y1`, scaling its alpha by each vertex's own alpha so anti-aliased fringes
+    // stay soft. The vertices should be drawn in opaque white.
+    pub(crate) fn shade_verts_vertical_gradient(
+        &self,
+        start: usize,
+        y0: f32,
+        y1: f32,
+        top: [f32; 4],
+        bottom: [f32; 4],
+    ) {
+        let vtx = unsafe {
+            let buffer = &(*self.draw_list).VtxBuffer;
+            std::slice::from_raw_parts_mut(buffer.Data, buffer.Size as usize)
+        };
+        for v in vtx.iter_mut().skip(start) {
+            let t = if y1 > y0 {
+                ((v.pos.y - y0) / (y1 - y0)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let [r, g, b, a] = std::array::from_fn(|i| top[i] + (bottom[i] - top[i]) * t);
+            let alpha = ImColor32::from_bits(v.col).to_rgba_f32s()[3];
+            v.col = ImColor32::from_rgba_f32s(r, g, b, a * alpha).to_bits();
+        }
+    }
+
     /// Returns a triangle with the given 3 vertices `p1`, `p2` and `p3`
     /// and color `c`.
     #[doc(alias = "AddTriangleFilled", alias = "AddTriangle")]
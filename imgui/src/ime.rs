@@ -9,14 +9,31 @@ pub trait ImeDataBackend: 'static {
 }
 
 /// IME data passed to the [ImeDataContext] callback
+///
+/// Dear ImGui doesn't track the IME composition (preedit) string, so it isn't available
+/// here; backends receive it from the OS directly.
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct PlatformImeData {
+    /// Whether a text input is active and the IME candidate window should be shown.
     pub want_visible: bool,
+    /// Position of the input cursor, in Dear ImGui's absolute coordinates.
     pub input_pos: [f32; 2],
+    /// Height of the line being edited, e.g. to place the candidate window below it.
     pub input_line_height: f32,
 }
 
+impl PlatformImeData {
+    /// Returns [`input_pos`](Self::input_pos) relative to the top-left corner of `viewport`,
+    /// i.e. in the coordinates of the OS window the viewport belongs to.
+    pub fn input_pos_in(&self, viewport: &crate::Viewport) -> [f32; 2] {
+        [
+            self.input_pos[0] - viewport.pos[0],
+            self.input_pos[1] - viewport.pos[1],
+        ]
+    }
+}
+
 pub(crate) struct ImeDataContext {
     backend: Box<dyn ImeDataBackend>,
 }
@@ -71,3 +88,41 @@ pub(crate) unsafe extern "C" fn set_ime_data(
         process::abort();
     });
 }
+
+#[test]
+fn test_set_ime_data_backend() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type Received = Vec<(crate::Id, PlatformImeData, [f32; 2])>;
+    struct Recorder(Rc<RefCell<Received>>);
+    impl ImeDataBackend for Recorder {
+        fn set_ime_data(&mut self, viewport: &mut crate::Viewport, data: PlatformImeData) {
+            let pos = data.input_pos_in(viewport);
+            self.0.borrow_mut().push((viewport.id, data, pos));
+        }
+    }
+
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let received = Rc::new(RefCell::new(Vec::new()));
+    ctx.set_ime_data_backend(Recorder(received.clone()));
+    let mut data = sys::ImGuiPlatformImeData {
+        WantVisible: true,
+        InputPos: sys::ImVec2 { x: 30.0, y: 40.0 },
+        InputLineHeight: 13.0,
+    };
+    let viewport = unsafe {
+        let viewport = sys::igGetMainViewport();
+        (*viewport).Pos = sys::ImVec2 { x: 10.0, y: 5.0 };
+        set_ime_data(sys::igGetCurrentContext(), viewport, &mut data);
+        viewport
+    };
+
+    let expected = PlatformImeData {
+        want_visible: true,
+        input_pos: [30.0, 40.0],
+        input_line_height: 13.0,
+    };
+    let id = crate::Id(unsafe { (*viewport).ID });
+    assert_eq!(*received.borrow(), [(id, expected, [20.0, 35.0])]);
+}
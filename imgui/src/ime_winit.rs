@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::window::Window;
+
+use crate::{ImeDataBackend, PlatformImeData, Viewport};
+
+/// An [`ImeDataBackend`] which forwards Dear ImGui's text input state to a winit window.
+///
+/// IME input is allowed on the window while a text input is active, and the IME candidate
+/// window is placed at the input cursor. Requires winit 0.30, and is enabled by the `winit`
+/// feature.
+///
+/// ```no_run
+/// # fn example(ctx: &mut imgui::Context, window: std::sync::Arc<winit::window::Window>) {
+/// ctx.set_ime_data_backend(imgui::WinitImeBackend::new(window));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WinitImeBackend {
+    window: Arc<Window>,
+    scale_factor: Option<f64>,
+    allowed: Option<bool>,
+}
+
+impl WinitImeBackend {
+    /// Creates a backend for `window`, converting Dear ImGui's coordinates to physical pixels
+    /// with the window's scale factor.
+    pub fn new(window: Arc<Window>) -> Self {
+        Self {
+            window,
+            scale_factor: None,
+            allowed: None,
+        }
+    }
+
+    /// Converts coordinates with `scale_factor` instead of the window's, e.g. when the
+    /// platform backend rounds or overrides the scale factor it gives Dear ImGui.
+    pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+}
+
+impl ImeDataBackend for WinitImeBackend {
+    fn set_ime_data(&mut self, viewport: &mut Viewport, data: PlatformImeData) {
+        // winit resets the IME state every time it's toggled, so only call it on changes
+        if self.allowed != Some(data.want_visible) {
+            self.window.set_ime_allowed(data.want_visible);
+            self.allowed = Some(data.want_visible);
+        }
+        if data.want_visible {
+            let scale_factor = self
+                .scale_factor
+                .unwrap_or_else(|| self.window.scale_factor());
+            let (position, size) = ime_cursor_area(
+                data.input_pos_in(viewport),
+                data.input_line_height,
+                scale_factor,
+            );
+            self.window.set_ime_cursor_area(position, size);
+        }
+    }
+}
+
+// converts the input cursor from Dear ImGui's coordinates to a one pixel wide area in the
+// window's physical pixels
+fn ime_cursor_area(
+    input_pos: [f32; 2],
+    line_height: f32,
+    scale_factor: f64,
+) -> (PhysicalPosition<f64>, PhysicalSize<f64>) {
+    let [x, y] = input_pos.map(|v| f64::from(v) * scale_factor);
+    let size = PhysicalSize::new(scale_factor, f64::from(line_height) * scale_factor);
+    (PhysicalPosition::new(x, y), size)
+}
+
+#[test]
+fn test_ime_cursor_area() {
+    let (position, size) = ime_cursor_area([10.0, 20.5], 13.0, 2.0);
+    assert_eq!(position, PhysicalPosition::new(20.0, 41.0));
+    assert_eq!(size, PhysicalSize::new(2.0, 26.0));
+
+    let (position, size) = ime_cursor_area([10.0, 20.0], 13.0, 1.0);
+    assert_eq!(position, PhysicalPosition::new(10.0, 20.0));
+    assert_eq!(size, PhysicalSize::new(1.0, 13.0));
+}
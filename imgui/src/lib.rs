@@ -102,6 +102,8 @@ pub use self::fonts::font::*;
 pub use self::fonts::glyph::*;
 pub use self::fonts::glyph_ranges::*;
 pub use self::ime::*;
+#[cfg(feature = "winit")]
+pub use self::ime_winit::*;
 pub use self::input::keyboard::*;
 pub use self::input::mouse::*;
 pub use self::input_widget::*;
@@ -158,6 +160,8 @@ pub mod drag_drop;
 pub mod draw_list;
 mod fonts;
 mod ime;
+#[cfg(feature = "winit")]
+mod ime_winit;
 mod input;
 mod input_widget;
 pub mod internal;
@@ -217,6 +221,10 @@ impl Context {
 pub struct Ui {
     /// our scratch sheet
     buffer: cell::UnsafeCell<string::UiBuffer>,
+    /// style overrides registered with `Context::set_viewport_style`
+    viewport_styles: std::collections::HashMap<Id, Style>,
+    /// settings set with `Context::style_extras_mut`
+    style_extras: StyleExtras,
 }
 
 impl Ui {
@@ -291,6 +299,11 @@ impl Ui {
         unsafe { *self.style() }
     }
 
+    /// Returns the imgui-rs specific style settings, see [`StyleExtras`].
+    pub fn style_extras(&self) -> &StyleExtras {
+        &self.style_extras
+    }
+
     /// This function, and the library's api, has been changed as of `0.9`!
     /// Do not use this function! Instead, use [`Context::render`],
     /// which does what this function in `0.8` used to do.
@@ -360,6 +373,28 @@ impl Ui {
     pub fn show_default_style_editor(&self) {
         unsafe { sys::igShowStyleEditor(std::ptr::null_mut()) };
     }
+    /// Renders a read-only list (not a window) of every value of the currently active style
+    /// which differs from `base`, one `key: base -> value` line each.
+    ///
+    /// Returns the number of differing values.
+    pub fn show_style_diff(&self, base: &Style) -> usize {
+        let diff = self.clone_style().diff(base);
+        let mut table = String::new();
+        // writing to a `String` can't fail
+        let _ = diff.fmt_table(&mut table);
+        for line in table.lines() {
+            self.text(line);
+        }
+        diff.len()
+    }
+    /// Returns the values of the currently active style which differ from `base` as a JSON
+    /// object, e.g. for saving a small theme tweak to a file. See [`StyleDiff::write_json`].
+    pub fn export_style_patch(&self, base: &Style) -> String {
+        let mut json = String::new();
+        // writing to a `String` can't fail
+        let _ = self.clone_style().diff(base).write_json(&mut json);
+        json
+    }
     /// Renders a basic help/info block (not a window)
     #[doc(alias = "ShowUserGuide")]
     pub fn show_user_guide(&self) {
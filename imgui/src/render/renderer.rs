@@ -1,7 +1,18 @@
-use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+use std::hash::BuildHasher;
+use std::ops::Range;
+use std::sync::{Arc, Mutex, PoisonError};
 
 /// An opaque texture identifier
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+///
+/// With the `serde` feature enabled, this (de)serializes as the inner `u64`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 #[repr(transparent)]
 pub struct TextureId(u64);
 
@@ -53,21 +64,125 @@ fn test_texture_id_memory_layout() {
     );
 }
 
-/// Generic texture mapping for use by renderers.
-#[derive(Debug)]
-pub struct Textures<T> {
-    textures: HashMap<u64, T>,
+#[test]
+#[cfg(feature = "serde")]
+fn test_texture_id_serde() {
+    let id = TextureId::new(42);
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "42");
+    assert_eq!(serde_json::from_str::<TextureId>(&json).unwrap(), id);
+}
+
+/// Sorts draw commands so that commands using the same texture are adjacent, to minimize
+/// texture binds in a renderer.
+///
+/// The sort is stable: commands using the same texture keep their relative order. Only
+/// reorder commands whose draw order doesn't matter, e.g. ones known not to overlap.
+pub fn sort_commands_by_texture<C>(cmds: &mut [C], key: impl Fn(&C) -> TextureId) {
+    cmds.sort_by_key(key);
+}
+
+#[test]
+fn test_sort_commands_by_texture() {
+    let mut cmds = [(2, 'a'), (1, 'b'), (2, 'c'), (0, 'd'), (1, 'e'), (2, 'f')];
+    sort_commands_by_texture(&mut cmds, |&(texture, _)| TextureId::new(texture));
+    assert_eq!(
+        cmds,
+        [(0, 'd'), (1, 'b'), (1, 'e'), (2, 'a'), (2, 'c'), (2, 'f')]
+    );
+}
+
+/// Hands out texture ids from a single id space, reusing freed ids.
+///
+/// Share one allocator between several [`Textures`] mappings (see
+/// [`Textures::with_allocator`]), e.g. for fonts, images and render targets, to make sure
+/// their ids never collide.
+#[derive(Clone, Debug, Default)]
+pub struct TextureIdAllocator {
     next: u64,
+    free: Vec<u64>,
+}
+
+impl TextureIdAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns an id which is not currently allocated, preferring previously freed ids.
+    pub fn alloc(&mut self) -> TextureId {
+        match self.free.pop() {
+            Some(id) => TextureId(id),
+            None => {
+                let id = self.next;
+                self.next += 1;
+                TextureId(id)
+            }
+        }
+    }
+
+    /// Makes `id` available to [`alloc`](Self::alloc) again. Ids which are not currently
+    /// allocated are ignored.
+    pub fn free(&mut self, id: TextureId) {
+        if id.0 < self.next && !self.free.contains(&id.0) {
+            self.free.push(id.0);
+        }
+    }
+
+    fn reserve(&mut self, count: u64) {
+        self.free.retain(|&id| id >= count);
+        self.next = self.next.max(count);
+    }
+
+    // takes `id` off the free list, as it was assigned manually
+    fn claim(&mut self, id: u64) {
+        self.free.retain(|&free| free != id);
+    }
+}
+
+#[test]
+fn test_texture_id_allocator() {
+    let mut allocator = TextureIdAllocator::new();
+    let a = allocator.alloc();
+    let b = allocator.alloc();
+    assert_ne!(a, b);
+    allocator.free(a);
+    allocator.free(a);
+    allocator.free(TextureId::new(10));
+    assert_eq!(allocator.alloc(), a);
+    assert_eq!(allocator.alloc(), TextureId::new(2));
+}
+
+/// Generic texture mapping for use by renderers.
+///
+/// The map, and the sets tracking dirty, modified and removed ids, are generic over their
+/// [`BuildHasher`], e.g. to use a faster hasher or a fixed-seed one for a reproducible
+/// iteration order. See [`with_hasher`](Self::with_hasher).
+#[derive(Clone, Debug)]
+pub struct Textures<T, S = RandomState> {
+    textures: HashMap<u64, T, S>,
+    ids: TextureIdAllocator,
+    dirty: HashSet<u64, S>,
+    revision: u64,
+    // the revision at which each stored texture last changed
+    modified: HashMap<u64, u64, S>,
+    // the revision at which each removed id was removed, until the id is stored again
+    removed: HashMap<u64, u64, S>,
+    allocator: Option<Arc<Mutex<TextureIdAllocator>>>,
 }
 
 /// We manually impl Default as `#[derive(Default)]`
 /// incorrectly requires `T: Default` which is
 /// not necessary at all.
-impl<T> Default for Textures<T> {
+impl<T, S: Default> Default for Textures<T, S> {
     fn default() -> Self {
         Self {
             textures: Default::default(),
-            next: Default::default(),
+            ids: Default::default(),
+            dirty: Default::default(),
+            revision: 0,
+            modified: Default::default(),
+            removed: Default::default(),
+            allocator: None,
         }
     }
 }
@@ -78,23 +193,284 @@ impl<T> Textures<T> {
     pub fn new() -> Self {
         Textures {
             textures: HashMap::new(),
-            next: 0,
+            ids: TextureIdAllocator::new(),
+            dirty: HashSet::new(),
+            revision: 0,
+            modified: HashMap::new(),
+            removed: HashMap::new(),
+            allocator: None,
         }
     }
 
+    /// Creates a mapping which allocates its ids from a shared [`TextureIdAllocator`], so
+    /// they never collide with the ids of other mappings using the same allocator.
+    ///
+    /// Removing a texture frees its id in the allocator.
+    pub fn with_allocator(allocator: Arc<Mutex<TextureIdAllocator>>) -> Self {
+        Textures {
+            allocator: Some(allocator),
+            ..Self::new()
+        }
+    }
+}
+
+impl<T, S: BuildHasher> Textures<T, S> {
+    /// Creates an empty mapping which uses `hasher` to hash texture ids.
+    pub fn with_hasher(hasher: S) -> Self
+    where
+        S: Clone,
+    {
+        Textures {
+            textures: HashMap::with_hasher(hasher.clone()),
+            ids: TextureIdAllocator::new(),
+            dirty: HashSet::with_hasher(hasher.clone()),
+            revision: 0,
+            modified: HashMap::with_hasher(hasher.clone()),
+            removed: HashMap::with_hasher(hasher),
+            allocator: None,
+        }
+    }
+
+    /// Creates an empty mapping which uses `hasher` to hash texture ids and allocates its ids
+    /// from a shared [`TextureIdAllocator`]. See [`with_allocator`](Textures::with_allocator).
+    pub fn with_hasher_and_allocator(hasher: S, allocator: Arc<Mutex<TextureIdAllocator>>) -> Self
+    where
+        S: Clone,
+    {
+        Textures {
+            allocator: Some(allocator),
+            ..Self::with_hasher(hasher)
+        }
+    }
+
+    // runs `f` with the shared allocator if there is one, or this mapping's own id space
+    fn with_ids<R>(&mut self, f: impl FnOnce(&mut TextureIdAllocator) -> R) -> R {
+        match &self.allocator {
+            // the allocator is never left half-updated, so a panic elsewhere can't poison it
+            Some(allocator) => f(&mut allocator.lock().unwrap_or_else(PoisonError::into_inner)),
+            None => f(&mut self.ids),
+        }
+    }
+
+    // records that the texture with the given id changed at a new revision
+    fn touch(&mut self, id: u64) {
+        self.revision += 1;
+        self.modified.insert(id, self.revision);
+        self.removed.remove(&id);
+    }
+
+    /// Inserts a texture under a new id. Ids of removed textures are reused before new ones
+    /// are handed out.
     pub fn insert(&mut self, texture: T) -> TextureId {
-        let id = self.next;
-        self.textures.insert(id, texture);
-        self.next += 1;
-        TextureId::from(id)
+        let id = self.with_ids(|ids| ids.alloc());
+        self.textures.insert(id.0, texture);
+        self.touch(id.0);
+        id
+    }
+
+    /// Ensures the ids `0..count` will never be handed out by [`insert`](Self::insert), so they
+    /// can be assigned manually with [`replace`](Self::replace).
+    ///
+    /// This is useful when restoring a layout which references fixed texture ids. Returns the
+    /// range of reserved ids.
+    pub fn ensure_ids(&mut self, count: usize) -> Range<u64> {
+        let count = count as u64;
+        self.with_ids(|ids| ids.reserve(count));
+        0..count
     }
 
     pub fn replace(&mut self, id: TextureId, texture: T) -> Option<T> {
+        self.with_ids(|ids| ids.claim(id.0));
+        self.touch(id.0);
         self.textures.insert(id.0, texture)
     }
 
     pub fn remove(&mut self, id: TextureId) -> Option<T> {
-        self.textures.remove(&id.0)
+        self.dirty.remove(&id.0);
+        let texture = self.textures.remove(&id.0);
+        if texture.is_some() {
+            self.modified.remove(&id.0);
+            self.revision += 1;
+            self.removed.insert(id.0, self.revision);
+            self.with_ids(|ids| ids.free(id));
+        }
+        texture
+    }
+
+    /// Removes every texture in `ids`, returning the removed textures in the same order, with
+    /// `None` for ids which weren't stored.
+    pub fn remove_many(&mut self, ids: &[TextureId]) -> Vec<Option<T>> {
+        ids.iter().map(|&id| self.remove(id)).collect()
+    }
+
+    /// Returns the number of textures currently stored.
+    pub fn len(&self) -> usize {
+        self.textures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.textures.is_empty()
+    }
+
+    pub fn contains(&self, id: TextureId) -> bool {
+        self.textures.contains_key(&id.0)
+    }
+
+    /// Removes every texture, e.g. between scene reloads.
+    ///
+    /// The ids of the removed textures are never handed out again, unlike with
+    /// [`remove`](Self::remove), so ids which Dear ImGui may still hold can't alias new
+    /// textures.
+    pub fn clear(&mut self) {
+        if !self.textures.is_empty() {
+            self.revision += 1;
+        }
+        for id in self.textures.drain().map(|(id, _)| id) {
+            self.removed.insert(id, self.revision);
+        }
+        self.dirty.clear();
+        self.modified.clear();
+    }
+
+    /// Marks the texture with the given id as changed, so it is visited by the next call to
+    /// [`for_each_dirty`](Self::for_each_dirty). Ids which aren't stored are ignored.
+    pub fn mark_dirty(&mut self, id: TextureId) {
+        if self.textures.contains_key(&id.0) {
+            self.dirty.insert(id.0);
+            self.touch(id.0);
+        }
+    }
+
+    pub fn is_dirty(&self, id: TextureId) -> bool {
+        self.dirty.contains(&id.0)
+    }
+
+    /// Returns the current revision of the mapping, which increases every time a texture is
+    /// inserted, replaced, recreated, marked dirty, or removed.
+    ///
+    /// Consumers can store the revision they last synced at and pass it to
+    /// [`changed_since`](Self::changed_since) and [`removed_since`](Self::removed_since)
+    /// later.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Iterates over the ids of the stored textures which changed after revision `rev`, in no
+    /// particular order.
+    ///
+    /// Unlike [`for_each_dirty`](Self::for_each_dirty) this doesn't consume anything, so
+    /// several consumers can each sync from their own revision.
+    pub fn changed_since(&self, rev: u64) -> impl Iterator<Item = TextureId> + '_ {
+        self.modified
+            .iter()
+            .filter(move |&(_, &modified)| modified > rev)
+            .map(|(&id, _)| TextureId(id))
+    }
+
+    /// Iterates over the ids which were removed after revision `rev`, with
+    /// [`remove`](Self::remove) or [`clear`](Self::clear), in no particular order, so
+    /// consumers can release their copies of the textures.
+    ///
+    /// An id which was removed and then stored again is only reported by
+    /// [`changed_since`](Self::changed_since).
+    ///
+    /// Removals are remembered until they are pruned with
+    /// [`prune_removed`](Self::prune_removed). Call it once every consumer has synced past a
+    /// revision; otherwise the removals of [`clear`](Self::clear), whose ids are never reused,
+    /// accumulate for the lifetime of the mapping.
+    pub fn removed_since(&self, rev: u64) -> impl Iterator<Item = TextureId> + '_ {
+        self.removed
+            .iter()
+            .filter(move |&(_, &removed)| removed > rev)
+            .map(|(&id, _)| TextureId(id))
+    }
+
+    /// Forgets the removals made at or before revision `rev`, typically the oldest revision
+    /// any consumer has synced to. [`removed_since`](Self::removed_since) no longer reports
+    /// them for revisions below `rev`.
+    pub fn prune_removed(&mut self, rev: u64) {
+        self.removed.retain(|_, &mut removed| removed > rev);
+    }
+
+    /// Calls `f` with every texture marked with [`mark_dirty`](Self::mark_dirty), e.g. to
+    /// upload all changes in one pass, then clears the dirty set.
+    pub fn for_each_dirty(&mut self, mut f: impl FnMut(TextureId, &mut T)) {
+        for id in self.dirty.drain() {
+            if let Some(texture) = self.textures.get_mut(&id) {
+                f(TextureId(id), texture);
+            }
+        }
+    }
+
+    /// Registers a placeholder texture to bind until the real texture has loaded.
+    ///
+    /// `make` receives the RGBA8 pixels of a `size` checkerboard (8 pixel cells of two
+    /// grays, row-major) and its size, and builds the backend texture from them.
+    pub fn insert_placeholder(
+        &mut self,
+        make: impl FnOnce(&[u8], [u32; 2]) -> T,
+        size: [u32; 2],
+    ) -> TextureId {
+        const CELL: u32 = 8;
+        let [width, height] = size;
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+        for y in 0..height {
+            for x in 0..width {
+                let gray = if (x / CELL + y / CELL) & 1 == 0 {
+                    0x80
+                } else {
+                    0xc0
+                };
+                pixels.extend_from_slice(&[gray, gray, gray, 0xff]);
+            }
+        }
+        let texture = make(&pixels, size);
+        self.insert(texture)
+    }
+
+    /// Replaces every texture with the result of passing it through `f`, keeping all ids.
+    ///
+    /// This is meant for recovering from a lost graphics device, where every backend
+    /// handle has to be recreated while the ids already handed to Dear ImGui stay valid.
+    pub fn recreate_all<F: FnMut(TextureId, T) -> T>(&mut self, mut f: F) {
+        let textures: Vec<_> = self.textures.drain().collect();
+        for (id, texture) in textures {
+            self.textures.insert(id, f(TextureId(id), texture));
+            self.touch(id);
+        }
+    }
+
+    /// Checks the internal invariants of the mapping, returning a description of the first
+    /// violation found.
+    ///
+    /// Every stored id must be below the next id [`insert`](Self::insert) will hand out, and
+    /// must not be on the allocator's free list; otherwise a later `insert` would silently
+    /// overwrite it. The first can happen when [`replace`](Self::replace) is called with an id
+    /// that was never returned by `insert`, the second when a stored id is passed to
+    /// [`TextureIdAllocator::free`] directly.
+    #[cfg(debug_assertions)]
+    pub fn debug_check_invariants(&self) -> Result<(), String> {
+        let check = |ids: &TextureIdAllocator| {
+            for &id in self.textures.keys() {
+                if id >= ids.next {
+                    return Err(format!(
+                        "texture id {} is not below the next id to be allocated ({})",
+                        id, ids.next
+                    ));
+                }
+                if ids.free.contains(&id) {
+                    return Err(format!(
+                        "texture id {} is stored but also free to be allocated",
+                        id
+                    ));
+                }
+            }
+            Ok(())
+        };
+        match &self.allocator {
+            Some(allocator) => check(&allocator.lock().unwrap_or_else(PoisonError::into_inner)),
+            None => check(&self.ids),
+        }
     }
 
     pub fn get(&self, id: TextureId) -> Option<&T> {
@@ -104,4 +480,579 @@ impl<T> Textures<T> {
     pub fn get_mut(&mut self, id: TextureId) -> Option<&mut T> {
         self.textures.get_mut(&id.0)
     }
+
+    /// Iterates over all stored textures, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (TextureId, &T)> + '_ {
+        self.textures
+            .iter()
+            .map(|(&id, texture)| (TextureId::from(id), texture))
+    }
+
+    /// Iterates over mutable references to all stored textures, in no particular order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (TextureId, &mut T)> + '_ {
+        self.textures
+            .iter_mut()
+            .map(|(&id, texture)| (TextureId::from(id), texture))
+    }
+
+    /// Iterates over the ids of all stored textures, in no particular order.
+    pub fn ids(&self) -> impl Iterator<Item = TextureId> + '_ {
+        self.textures.keys().map(|&id| TextureId::from(id))
+    }
+}
+
+impl<T: std::fmt::Debug, S: BuildHasher> Textures<T, S> {
+    /// Returns one `id: texture` line per stored texture, sorted by id, for troubleshooting
+    /// mismatches between the ids Dear ImGui draws with and the renderer's textures.
+    pub fn debug_manifest(&self) -> String {
+        let mut ids: Vec<_> = self.textures.keys().copied().collect();
+        ids.sort_unstable();
+        ids.into_iter()
+            .map(|id| format!("{}: {:?}\n", id, self.textures[&id]))
+            .collect()
+    }
+}
+
+#[test]
+fn test_textures_debug_manifest() {
+    let mut textures = Textures::new();
+    textures.ensure_ids(2);
+    textures.insert("font");
+    textures.replace(TextureId::new(0), "white");
+    textures.insert("icons");
+    assert_eq!(
+        textures.debug_manifest(),
+        "0: \"white\"\n2: \"font\"\n3: \"icons\"\n"
+    );
+}
+
+#[test]
+fn test_textures_ensure_ids() {
+    let mut textures = Textures::new();
+    assert_eq!(textures.ensure_ids(5), 0..5);
+    assert_eq!(textures.insert(()), TextureId::new(5));
+
+    // never moves `next` backwards
+    textures.ensure_ids(2);
+    assert_eq!(textures.insert(()), TextureId::new(6));
+}
+
+#[test]
+fn test_textures_with_hasher() {
+    use std::hash::BuildHasherDefault;
+
+    #[derive(Default)]
+    struct IdHasher(u64);
+    impl std::hash::Hasher for IdHasher {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+        fn write(&mut self, _: &[u8]) {
+            unreachable!()
+        }
+        fn write_u64(&mut self, n: u64) {
+            self.0 = n;
+        }
+    }
+
+    let mut textures = Textures::with_hasher(BuildHasherDefault::<IdHasher>::default());
+    let a = textures.insert("a");
+    assert_eq!(textures.get(a), Some(&"a"));
+    assert_eq!(textures.remove(a), Some("a"));
+
+    let b = textures.insert("b");
+    let c = textures.insert("c");
+    let rev = textures.revision();
+    textures.mark_dirty(b);
+    textures.remove(c);
+    assert!(textures.is_dirty(b));
+    assert_eq!(textures.changed_since(rev).collect::<Vec<_>>(), [b]);
+    assert_eq!(textures.removed_since(rev).collect::<Vec<_>>(), [c]);
+
+    let default: Textures<(), BuildHasherDefault<IdHasher>> = Textures::default();
+    assert!(default.is_empty());
+
+    let allocator = Arc::new(Mutex::new(TextureIdAllocator::new()));
+    let mut shared = Textures::with_hasher_and_allocator(
+        BuildHasherDefault::<IdHasher>::default(),
+        allocator.clone(),
+    );
+    let mut other = Textures::with_allocator(allocator);
+    assert_ne!(shared.insert(()), other.insert(()));
+}
+
+#[test]
+fn test_textures_iter() {
+    let mut textures = Textures::new();
+    let a = textures.insert(1);
+    let b = textures.insert(2);
+    for (_, value) in textures.iter_mut() {
+        *value *= 10;
+    }
+    let mut entries: Vec<_> = textures.iter().map(|(id, &v)| (id, v)).collect();
+    entries.sort_unstable();
+    assert_eq!(entries, [(a, 10), (b, 20)]);
+    let mut ids: Vec<_> = textures.ids().collect();
+    ids.sort_unstable();
+    assert_eq!(ids, [a, b]);
+}
+
+#[test]
+fn test_textures_remove_many() {
+    let mut textures = Textures::new();
+    let a = textures.insert('a');
+    let b = textures.insert('b');
+    let c = textures.insert('c');
+    let d = textures.insert('d');
+    assert_eq!(
+        textures.remove_many(&[c, a, TextureId::new(10), b]),
+        [Some('c'), Some('a'), None, Some('b')]
+    );
+    assert!(!textures.contains(a) && !textures.contains(b) && !textures.contains(c));
+    assert_eq!(textures.len(), 1);
+    assert!(textures.contains(d));
+}
+
+#[test]
+fn test_textures_len_and_clear() {
+    let mut textures = Textures::new();
+    assert!(textures.is_empty());
+    assert_eq!(textures.len(), 0);
+    assert!(!textures.contains(TextureId::new(0)));
+    textures.clear();
+    assert!(textures.is_empty());
+
+    let a = textures.insert(());
+    let b = textures.insert(());
+    textures.mark_dirty(a);
+    assert_eq!(textures.len(), 2);
+    assert!(!textures.is_empty());
+    assert!(textures.contains(a) && textures.contains(b));
+
+    textures.clear();
+    assert!(textures.is_empty());
+    assert!(!textures.contains(a) && !textures.is_dirty(a));
+    assert_eq!(textures.insert(()), TextureId::new(2));
+}
+
+#[test]
+fn test_textures_reuse_removed_ids() {
+    let mut textures = Textures::new();
+    let _a = textures.insert('a');
+    let b = textures.insert('b');
+    let _c = textures.insert('c');
+    textures.remove(b);
+    assert_eq!(textures.len(), 2);
+    assert_eq!(textures.insert('d'), b);
+    assert_eq!(textures.insert('e'), TextureId::new(3));
+    assert_eq!(textures.len(), 4);
+
+    // manually assigned ids are never handed out again
+    textures.remove(b);
+    textures.replace(b, 'f');
+    assert_eq!(textures.insert('g'), TextureId::new(4));
+}
+
+#[test]
+fn test_textures_is_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<Textures<u32>>();
+    assert_send::<TextureIdAllocator>();
+}
+
+#[test]
+fn test_textures_shared_allocator() {
+    let allocator = Arc::new(Mutex::new(TextureIdAllocator::new()));
+    let mut fonts = Textures::with_allocator(allocator.clone());
+    let mut images = Textures::with_allocator(allocator);
+    fonts.ensure_ids(1);
+
+    let mut ids = HashSet::new();
+    for i in 0..8 {
+        assert!(ids.insert(fonts.insert(i)));
+        assert!(ids.insert(images.insert(i)));
+    }
+    assert!(!ids.contains(&TextureId::new(0)));
+
+    let freed = fonts.insert(8);
+    fonts.remove(freed);
+    for i in 0..4 {
+        assert!(ids.insert(images.insert(i)));
+        assert!(ids.insert(fonts.insert(i)));
+    }
+}
+
+#[test]
+fn test_textures_recreate_all() {
+    let mut textures = Textures::new();
+    let a = textures.insert(1);
+    let b = textures.insert(2);
+    textures.recreate_all(|id, value| value * 10 + id.id() as i32);
+    assert_eq!(textures.get(a), Some(&10));
+    assert_eq!(textures.get(b), Some(&21));
+    assert_eq!(textures.insert(3), TextureId::new(2));
+}
+
+#[test]
+fn test_textures_insert_placeholder() {
+    let mut textures = Textures::new();
+    let id = textures.insert_placeholder(
+        |pixels, [width, height]| {
+            assert_eq!(pixels.len(), (width * height * 4) as usize);
+            (pixels[0], pixels[8 * 4], width)
+        },
+        [16, 4],
+    );
+    assert_eq!(textures.get(id), Some(&(0x80, 0xc0, 16)));
+}
+
+#[test]
+fn test_textures_for_each_dirty() {
+    let mut textures = Textures::new();
+    let a = textures.insert(0);
+    let b = textures.insert(0);
+    let c = textures.insert(0);
+    textures.mark_dirty(a);
+    textures.mark_dirty(c);
+    textures.mark_dirty(TextureId::new(10));
+
+    let mut visited = Vec::new();
+    textures.for_each_dirty(|id, value| {
+        *value += 1;
+        visited.push(id.id());
+    });
+    visited.sort_unstable();
+    assert_eq!(visited, [a.id(), c.id()]);
+    assert_eq!(textures.get(b), Some(&0));
+    assert!(!textures.is_dirty(a) && !textures.is_dirty(c));
+
+    textures.for_each_dirty(|_, _| panic!("dirty set should be empty"));
+}
+
+#[test]
+fn test_textures_changed_since() {
+    let mut textures = Textures::new();
+    let a = textures.insert("a");
+    let b = textures.insert("b");
+    let c = textures.insert("c");
+    let e = textures.insert("e");
+    let rev = textures.revision();
+    assert_eq!(textures.changed_since(rev).count(), 0);
+    assert_eq!(textures.removed_since(rev).count(), 0);
+
+    textures.mark_dirty(a);
+    textures.replace(c, "c2");
+    textures.remove(e);
+    let d = textures.insert("d");
+    assert_eq!(d, e);
+    textures.remove(d);
+    textures.remove(d);
+    let mut changed: Vec<_> = textures.changed_since(rev).map(|id| id.id()).collect();
+    changed.sort_unstable();
+    assert_eq!(changed, [a.id(), c.id()]);
+    assert_eq!(textures.removed_since(rev).collect::<Vec<_>>(), [d]);
+
+    let mut all: Vec<_> = textures.changed_since(0).map(|id| id.id()).collect();
+    all.sort_unstable();
+    assert_eq!(all, [a.id(), b.id(), c.id()]);
+    assert_eq!(textures.changed_since(textures.revision()).count(), 0);
+
+    let rev = textures.revision();
+    textures.clear();
+    assert!(textures.revision() > rev);
+    let mut removed: Vec<_> = textures.removed_since(rev).map(|id| id.id()).collect();
+    removed.sort_unstable();
+    assert_eq!(removed, [a.id(), b.id(), c.id()]);
+    assert_eq!(textures.removed_since(textures.revision()).count(), 0);
+
+    let f = textures.insert("f");
+    let cleared = textures.revision();
+    textures.remove(f);
+    textures.prune_removed(cleared);
+    assert_eq!(textures.removed_since(rev).collect::<Vec<_>>(), [f]);
+    textures.prune_removed(textures.revision());
+    assert_eq!(textures.removed_since(0).count(), 0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn test_textures_debug_check_invariants() {
+    let mut textures = Textures::new();
+    let id = textures.insert(());
+    textures.replace(id, ());
+    assert!(textures.debug_check_invariants().is_ok());
+
+    textures.replace(TextureId::new(10), ());
+    assert!(textures.debug_check_invariants().is_err());
+
+    let allocator = Arc::new(Mutex::new(TextureIdAllocator::new()));
+    let mut textures = Textures::with_allocator(allocator.clone());
+    let id = textures.insert(());
+    assert!(textures.debug_check_invariants().is_ok());
+    allocator.lock().unwrap().free(id);
+    assert!(textures.debug_check_invariants().is_err());
+}
+
+/// A texture whose size in memory is known, for use with [`SizedTextures`].
+pub trait SizedTexture {
+    /// Returns the number of bytes this texture occupies (typically on the GPU).
+    fn size_bytes(&self) -> u64;
+}
+
+/// A [`Textures`] mapping which keeps a running total of the memory used by its textures.
+///
+/// Useful for displaying texture memory usage in a debug overlay.
+#[derive(Debug)]
+pub struct SizedTextures<T> {
+    textures: Textures<T>,
+    total_bytes: u64,
+}
+
+impl<T> Default for SizedTextures<T> {
+    fn default() -> Self {
+        Self {
+            textures: Default::default(),
+            total_bytes: 0,
+        }
+    }
+}
+
+impl<T: SizedTexture> SizedTextures<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, texture: T) -> TextureId {
+        self.total_bytes += texture.size_bytes();
+        self.textures.insert(texture)
+    }
+
+    pub fn replace(&mut self, id: TextureId, texture: T) -> Option<T> {
+        self.total_bytes += texture.size_bytes();
+        let old = self.textures.replace(id, texture);
+        if let Some(old) = &old {
+            self.total_bytes -= old.size_bytes();
+        }
+        old
+    }
+
+    pub fn remove(&mut self, id: TextureId) -> Option<T> {
+        let old = self.textures.remove(id);
+        if let Some(old) = &old {
+            self.total_bytes -= old.size_bytes();
+        }
+        old
+    }
+
+    pub fn get(&self, id: TextureId) -> Option<&T> {
+        self.textures.get(id)
+    }
+
+    /// Returns the sum of [`SizedTexture::size_bytes`] over all textures currently stored.
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    /// Returns the underlying texture mapping.
+    pub fn textures(&self) -> &Textures<T> {
+        &self.textures
+    }
+}
+
+#[test]
+fn test_sized_textures_total_bytes() {
+    struct Tex(u64);
+    impl SizedTexture for Tex {
+        fn size_bytes(&self) -> u64 {
+            self.0
+        }
+    }
+
+    let mut textures = SizedTextures::new();
+    let a = textures.insert(Tex(100));
+    let b = textures.insert(Tex(20));
+    assert_eq!(textures.total_bytes(), 120);
+
+    textures.replace(a, Tex(50));
+    assert_eq!(textures.total_bytes(), 70);
+
+    textures.remove(b);
+    assert_eq!(textures.total_bytes(), 50);
+    assert!(textures.remove(b).is_none());
+    assert_eq!(textures.total_bytes(), 50);
+}
+
+/// A [`Textures`] mapping which remembers the order its textures were added in.
+///
+/// Iteration follows insertion order, which makes dumps of the mapping deterministic
+/// (e.g. for golden tests), unlike the underlying `HashMap`.
+#[derive(Debug)]
+pub struct OrderedTextures<T> {
+    textures: Textures<T>,
+    order: Vec<u64>,
+}
+
+impl<T> Default for OrderedTextures<T> {
+    fn default() -> Self {
+        Self {
+            textures: Default::default(),
+            order: Vec::new(),
+        }
+    }
+}
+
+impl<T> OrderedTextures<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, texture: T) -> TextureId {
+        let id = self.textures.insert(texture);
+        self.order.push(id.0);
+        id
+    }
+
+    /// Replaces the texture with the given id. Ids which were not present yet are
+    /// ordered after every existing texture.
+    pub fn replace(&mut self, id: TextureId, texture: T) -> Option<T> {
+        let old = self.textures.replace(id, texture);
+        if old.is_none() {
+            self.order.push(id.0);
+        }
+        old
+    }
+
+    pub fn remove(&mut self, id: TextureId) -> Option<T> {
+        let old = self.textures.remove(id);
+        if old.is_some() {
+            self.order.retain(|&other| other != id.0);
+        }
+        old
+    }
+
+    pub fn get(&self, id: TextureId) -> Option<&T> {
+        self.textures.get(id)
+    }
+
+    pub fn get_mut(&mut self, id: TextureId) -> Option<&mut T> {
+        self.textures.get_mut(id)
+    }
+
+    /// Iterates over all textures in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (TextureId, &T)> + '_ {
+        self.order
+            .iter()
+            .map(move |&id| (TextureId(id), &self.textures.textures[&id]))
+    }
+
+    /// Returns the underlying texture mapping.
+    pub fn textures(&self) -> &Textures<T> {
+        &self.textures
+    }
+}
+
+#[test]
+fn test_ordered_textures_iter() {
+    let mut textures = OrderedTextures::new();
+    let a = textures.insert("a");
+    let b = textures.insert("b");
+    textures.replace(TextureId::new(7), "c");
+    let d = textures.insert("d");
+    textures.remove(b);
+    textures.replace(a, "A");
+
+    let order: Vec<_> = textures.iter().collect();
+    assert_eq!(order, [(a, &"A"), (TextureId::new(7), &"c"), (d, &"d")]);
+}
+
+/// A [`Textures`] mapping whose textures are reference counted, for textures shared by
+/// several owners.
+///
+/// A texture starts with one reference when inserted and is only removed once every
+/// reference has been released.
+#[derive(Debug)]
+pub struct RcTextures<T> {
+    textures: Textures<T>,
+    refs: HashMap<u64, usize>,
+}
+
+impl<T> Default for RcTextures<T> {
+    fn default() -> Self {
+        Self {
+            textures: Default::default(),
+            refs: HashMap::new(),
+        }
+    }
+}
+
+impl<T> RcTextures<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a texture with a reference count of one.
+    pub fn insert(&mut self, texture: T) -> TextureId {
+        let id = self.textures.insert(texture);
+        self.refs.insert(id.0, 1);
+        id
+    }
+
+    /// Adds a reference to the texture with the given id. Returns false if there is no such
+    /// texture.
+    pub fn retain_ref(&mut self, id: TextureId) -> bool {
+        match self.refs.get_mut(&id.0) {
+            Some(count) => {
+                *count += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Releases a reference to the texture with the given id, returning the texture if that
+    /// was its last reference.
+    pub fn release_ref(&mut self, id: TextureId) -> Option<T> {
+        let count = self.refs.get_mut(&id.0)?;
+        *count -= 1;
+        if *count > 0 {
+            return None;
+        }
+        self.refs.remove(&id.0);
+        self.textures.remove(id)
+    }
+
+    /// Returns the number of references to the texture with the given id, or 0 if there is
+    /// no such texture.
+    pub fn ref_count(&self, id: TextureId) -> usize {
+        self.refs.get(&id.0).copied().unwrap_or(0)
+    }
+
+    pub fn get(&self, id: TextureId) -> Option<&T> {
+        self.textures.get(id)
+    }
+
+    pub fn get_mut(&mut self, id: TextureId) -> Option<&mut T> {
+        self.textures.get_mut(id)
+    }
+
+    /// Returns the underlying texture mapping.
+    pub fn textures(&self) -> &Textures<T> {
+        &self.textures
+    }
+}
+
+#[test]
+fn test_rc_textures_release_ref() {
+    let mut textures = RcTextures::new();
+    let id = textures.insert("shared");
+    assert!(textures.retain_ref(id));
+    assert_eq!(textures.ref_count(id), 2);
+
+    assert_eq!(textures.release_ref(id), None);
+    assert_eq!(textures.get(id), Some(&"shared"));
+    assert_eq!(textures.release_ref(id), Some("shared"));
+    assert_eq!(textures.get(id), None);
+    assert_eq!(textures.ref_count(id), 0);
+    assert!(!textures.retain_ref(id));
+    assert_eq!(textures.release_ref(id), None);
 }
@@ -1,9 +1,10 @@
 use crate::fonts::atlas::FontId;
 use crate::internal::RawCast;
 use crate::math::MintVec4;
-use crate::style::{StyleColor, StyleVar};
+use crate::style::{Style, StyleColor, StyleVar, ThemeFamily};
 use crate::sys;
-use crate::Ui;
+use crate::{Id, Ui};
+use std::marker::PhantomData;
 use std::os::raw::c_char;
 
 /// # Parameter stacks (shared)
@@ -84,6 +85,139 @@ impl Ui {
         unsafe { push_style_var(style_var) };
         StyleStackToken::new(self)
     }
+
+    /// Multiplies the global [`alpha`](Style::alpha) by `alpha` by pushing a change to the
+    /// style stack, e.g. to fade a window in over a few frames.
+    ///
+    /// Unlike [`begin_disabled`](Self::begin_disabled), this only changes opacity. Returns an
+    /// `AlphaToken` that restores the previous alpha when dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// # let fade_in = 0.5;
+    /// let _alpha = ui.push_alpha(fade_in);
+    /// ui.window("Fading").build(|| ui.text("Hello"));
+    /// ```
+    pub fn push_alpha(&self, alpha: f32) -> AlphaToken<'_> {
+        let current = unsafe { (*sys::igGetStyle()).Alpha };
+        unsafe { push_style_var(StyleVar::Alpha(current * alpha)) };
+        AlphaToken::new(self)
+    }
+
+    /// Switches to `style` for everything submitted until the returned token is dropped, by
+    /// pushing every style var and color which differs from the current style.
+    ///
+    /// Only the fields which have a [`StyleVar`] can be pushed; other fields of `style` are
+    /// ignored. Any style colors or vars pushed while the token is alive must be popped before
+    /// it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let mut panel_style = ui.clone_style();
+    /// panel_style.frame_rounding = 6.0;
+    /// let _style = ui.push_style(&panel_style);
+    /// ui.button("Rounded");
+    /// ```
+    pub fn push_style(&self, style: &Style) -> PushedStyleToken<'_> {
+        let current = self.clone_style();
+        let mut var_count = 0;
+        for var in style.vars() {
+            if current.get_var(var) != var {
+                unsafe { push_style_var(var) };
+                var_count += 1;
+            }
+        }
+        let mut color_count = 0;
+        for (color, &value) in style.iter_colors() {
+            if current[color] != value {
+                unsafe { sys::igPushStyleColor_Vec4(color as i32, value.into()) };
+                color_count += 1;
+            }
+        }
+        PushedStyleToken {
+            var_count,
+            color_count,
+            _ui: PhantomData,
+        }
+    }
+
+    /// Pushes every style var and color of the override registered for the given viewport
+    /// with [`Context::set_viewport_style`](crate::Context::set_viewport_style) which differs
+    /// from the current style, like [`Ui::push_style`].
+    ///
+    /// Returns `None` if the viewport has no override. Windows opened with
+    /// [`Window::viewport`](crate::Window::viewport) push their viewport's override
+    /// automatically.
+    pub fn push_viewport_style(&self, viewport: Id) -> Option<PushedStyleToken<'_>> {
+        let style = self.viewport_styles.get(&viewport)?;
+        Some(self.push_style(style))
+    }
+
+    /// Pushes the whole palette of a built-in preset for the duration of `f`, popping it
+    /// afterwards.
+    ///
+    /// This is useful for previewing a light theme inside a dark application, for example.
+    /// The colors are popped even if `f` panics.
+    pub fn with_theme_family(&self, family: ThemeFamily, f: impl FnOnce(&Ui)) {
+        let _tokens: Vec<_> = StyleColor::VARIANTS
+            .iter()
+            .zip(family.colors())
+            .map(|(&color, value)| self.push_style_color(color, value))
+            .collect();
+        f(self)
+    }
+}
+
+/// Tracks the style vars and colors pushed with [`Ui::push_style`], popping them when
+/// dropped.
+#[must_use]
+pub struct PushedStyleToken<'ui> {
+    var_count: i32,
+    color_count: i32,
+    _ui: PhantomData<&'ui Ui>,
+}
+
+impl PushedStyleToken<'_> {
+    /// Pops every style var and color that was pushed.
+    pub fn end(self) {
+        // left empty for drop
+    }
+}
+
+impl Drop for PushedStyleToken<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            sys::igPopStyleVar(self.var_count);
+            sys::igPopStyleColor(self.color_count);
+        }
+    }
+}
+
+impl StyleVar {
+    /// Pushes this style var for the duration of `f`, popping it afterwards.
+    ///
+    /// The var is popped even if `f` panics.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let clicked = StyleVar::FrameRounding(4.0).scope(ui, |ui| ui.button("Rounded"));
+    /// ```
+    pub fn scope<R>(self, ui: &Ui, f: impl FnOnce(&Ui) -> R) -> R {
+        let _token = ui.push_style_var(self);
+        f(ui)
+    }
 }
 
 create_token!(
@@ -134,6 +268,15 @@ impl StyleStackToken<'_> {
     }
 }
 
+create_token!(
+    /// Tracks a global alpha change made with [`Ui::push_alpha`] that can be popped by
+    /// calling `.end()` or by dropping.
+    pub struct AlphaToken<'ui>;
+
+    /// Restores the previous global alpha.
+    drop { sys::igPopStyleVar(1) }
+);
+
 #[inline]
 unsafe fn push_style_var(style_var: StyleVar) {
     use crate::style::StyleVar::*;
@@ -173,6 +316,33 @@ unsafe fn push_style_var(style_var: StyleVar) {
             igPushStyleVar_Vec2(sys::ImGuiStyleVar_SelectableTextAlign as i32, v.into())
         }
         CellPadding(v) => igPushStyleVar_Vec2(sys::ImGuiStyleVar_CellPadding as i32, v.into()),
+        DisabledAlpha(v) => igPushStyleVar_Float(sys::ImGuiStyleVar_DisabledAlpha as i32, v),
+        TabBorderSize(v) => igPushStyleVar_Float(sys::ImGuiStyleVar_TabBorderSize as i32, v),
+        TabBarBorderSize(v) => igPushStyleVar_Float(sys::ImGuiStyleVar_TabBarBorderSize as i32, v),
+        TabBarOverlineSize(v) => {
+            igPushStyleVar_Float(sys::ImGuiStyleVar_TabBarOverlineSize as i32, v)
+        }
+        TableAngledHeadersAngle(v) => {
+            igPushStyleVar_Float(sys::ImGuiStyleVar_TableAngledHeadersAngle as i32, v)
+        }
+        TableAngledHeadersTextAlign(v) => igPushStyleVar_Vec2(
+            sys::ImGuiStyleVar_TableAngledHeadersTextAlign as i32,
+            v.into(),
+        ),
+        SeparatorTextBorderSize(v) => {
+            igPushStyleVar_Float(sys::ImGuiStyleVar_SeparatorTextBorderSize as i32, v)
+        }
+        SeparatorTextAlign(v) => {
+            igPushStyleVar_Vec2(sys::ImGuiStyleVar_SeparatorTextAlign as i32, v.into())
+        }
+        SeparatorTextPadding(v) => {
+            igPushStyleVar_Vec2(sys::ImGuiStyleVar_SeparatorTextPadding as i32, v.into())
+        }
+        ImageBorderSize(v) => igPushStyleVar_Float(sys::ImGuiStyleVar_ImageBorderSize as i32, v),
+        #[cfg(feature = "docking")]
+        DockingSeparatorSize(v) => {
+            igPushStyleVar_Float(sys::ImGuiStyleVar_DockingSeparatorSize as i32, v)
+        }
     }
 }
 
@@ -472,3 +642,175 @@ bitflags::bitflags! {
         const ALLOW_DUPLICATE_ID = sys::ImGuiItemFlags_AllowDuplicateId;
     }
 }
+
+#[test]
+fn test_push_viewport_style() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let compact = Style {
+        window_padding: [2.0, 2.0],
+        ..*ctx.style()
+    };
+    let global_padding = ctx.style().window_padding;
+    ctx.set_viewport_style(Id(2), compact);
+    let ui = ctx.new_frame();
+    let depth = || unsafe {
+        let ctx = &*sys::igGetCurrentContext();
+        (ctx.StyleVarStack.Size, ctx.ColorStack.Size)
+    };
+    let before = depth();
+
+    assert!(ui.push_viewport_style(Id(1)).is_none());
+    {
+        let _token = ui.push_viewport_style(Id(2)).unwrap();
+        assert_eq!(depth(), (before.0 + 1, before.1));
+        assert_eq!(ui.clone_style().window_padding, [2.0, 2.0]);
+    }
+    assert_eq!(depth(), before);
+
+    let padding = ui
+        .window("Tool")
+        .viewport(Id(2))
+        .build(|| ui.clone_style().window_padding);
+    assert_eq!(padding, Some([2.0, 2.0]));
+    let padding = ui
+        .window("Main")
+        .viewport(Id(1))
+        .build(|| ui.clone_style().window_padding);
+    assert_eq!(padding, Some(global_padding));
+    assert_eq!(depth(), before);
+}
+
+#[test]
+fn test_style_var_scope() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let alpha = ui.clone_style().alpha;
+    let scoped = StyleVar::Alpha(0.25).scope(ui, |ui| ui.clone_style().alpha);
+    assert_eq!(scoped, 0.25);
+    assert_eq!(ui.clone_style().alpha, alpha);
+}
+
+#[test]
+fn test_with_theme_family() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let color = |c: StyleColor| {
+        let v = unsafe { *sys::igGetStyleColorVec4(c as i32) };
+        [v.x, v.y, v.z, v.w]
+    };
+    let before: Vec<_> = StyleColor::VARIANTS.iter().map(|&c| color(c)).collect();
+    let light = ThemeFamily::Light.colors();
+    ui.with_theme_family(ThemeFamily::Light, |_| {
+        for c in StyleColor::VARIANTS {
+            assert_eq!(color(c), light[c as usize]);
+        }
+    });
+    let after: Vec<_> = StyleColor::VARIANTS.iter().map(|&c| color(c)).collect();
+    assert_eq!(after, before);
+}
+
+#[test]
+fn test_push_every_style_var() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let vars = [
+        StyleVar::Alpha(0.5),
+        StyleVar::WindowPadding([1.0, 2.0]),
+        StyleVar::WindowRounding(1.0),
+        StyleVar::WindowBorderSize(1.0),
+        StyleVar::WindowMinSize([40.0, 40.0]),
+        StyleVar::WindowTitleAlign([0.5, 0.5]),
+        StyleVar::ChildRounding(1.0),
+        StyleVar::ChildBorderSize(1.0),
+        StyleVar::PopupRounding(1.0),
+        StyleVar::PopupBorderSize(1.0),
+        StyleVar::FramePadding([1.0, 2.0]),
+        StyleVar::FrameRounding(1.0),
+        StyleVar::FrameBorderSize(1.0),
+        StyleVar::ItemSpacing([1.0, 2.0]),
+        StyleVar::ItemInnerSpacing([1.0, 2.0]),
+        StyleVar::IndentSpacing(1.0),
+        StyleVar::ScrollbarSize(1.0),
+        StyleVar::ScrollbarRounding(1.0),
+        StyleVar::GrabMinSize(1.0),
+        StyleVar::GrabRounding(1.0),
+        StyleVar::TabRounding(1.0),
+        StyleVar::ButtonTextAlign([0.5, 0.5]),
+        StyleVar::SelectableTextAlign([0.5, 0.5]),
+        StyleVar::CellPadding([1.0, 2.0]),
+        StyleVar::DisabledAlpha(0.25),
+        StyleVar::TabBorderSize(1.0),
+        StyleVar::TabBarBorderSize(2.0),
+        StyleVar::TabBarOverlineSize(2.0),
+        StyleVar::TableAngledHeadersAngle(0.5),
+        StyleVar::TableAngledHeadersTextAlign([0.5, 0.5]),
+        StyleVar::SeparatorTextBorderSize(2.0),
+        StyleVar::SeparatorTextAlign([0.5, 0.5]),
+        StyleVar::SeparatorTextPadding([1.0, 2.0]),
+        StyleVar::ImageBorderSize(1.0),
+        #[cfg(feature = "docking")]
+        StyleVar::DockingSeparatorSize(3.0),
+    ];
+    let disabled_alpha = || unsafe { (*sys::igGetStyle()).DisabledAlpha };
+    let before = disabled_alpha();
+    {
+        let _tokens: Vec<_> = vars.iter().map(|&v| ui.push_style_var(v)).collect();
+        assert_eq!(disabled_alpha(), 0.25);
+    }
+    assert_eq!(disabled_alpha(), before);
+}
+
+#[test]
+fn test_push_alpha() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.style_mut().alpha = 0.8;
+    let ui = ctx.new_frame();
+    let alpha = || unsafe { (*sys::igGetStyle()).Alpha };
+    {
+        let _outer = ui.push_alpha(0.5);
+        assert_eq!(alpha(), 0.4);
+        {
+            let _inner = ui.push_alpha(0.5);
+            assert_eq!(alpha(), 0.2);
+        }
+        assert_eq!(alpha(), 0.4);
+    }
+    assert_eq!(alpha(), 0.8);
+}
+
+#[test]
+fn test_push_style() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let depth = || unsafe {
+        let ctx = &*sys::igGetCurrentContext();
+        (ctx.StyleVarStack.Size, ctx.ColorStack.Size)
+    };
+    let before = depth();
+    {
+        let _style = ui.push_style(&ui.clone_style());
+        assert_eq!(depth(), before);
+    }
+
+    let mut style = ui.clone_style();
+    style.alpha = 0.5;
+    {
+        let _style = ui.push_style(&style);
+        assert_eq!(depth(), (before.0 + 1, before.1));
+        assert_eq!(unsafe { (*sys::igGetStyle()).Alpha }, 0.5);
+    }
+    assert_eq!(depth(), before);
+
+    style[StyleColor::Button] = [1.0, 0.0, 0.0, 1.0];
+    style[StyleColor::Text] = [0.0, 1.0, 0.0, 1.0];
+    {
+        let _style = ui.push_style(&style);
+        assert_eq!(depth(), (before.0 + 1, before.1 + 2));
+        let button = unsafe { *sys::igGetStyleColorVec4(StyleColor::Button as i32) };
+        assert_eq!(
+            [button.x, button.y, button.z, button.w],
+            [1.0, 0.0, 0.0, 1.0]
+        );
+    }
+    assert_eq!(depth(), before);
+}
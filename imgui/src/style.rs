@@ -1,7 +1,11 @@
 use std::convert::TryFrom;
 use std::fmt;
+use std::mem;
 use std::ops::{Index, IndexMut};
+use std::ptr;
 
+use crate::color::ImColor32;
+use crate::draw_list::DrawFlags;
 use crate::internal::RawCast;
 use crate::Direction;
 use crate::{sys, HoveredFlags};
@@ -25,6 +29,12 @@ pub struct Style {
     ///
     /// Generally set to 0.0 or 1.0 (other values are not well tested and cost more CPU/GPU).
     pub window_border_size: f32,
+    /// Hit-testing extent outside and inside the window border used for resizing, which also
+    /// extends the area in which the window counts as hovered.
+    ///
+    /// Generally meaningfully larger than `window_border_size` to make borders easy to reach.
+    /// Must be greater than 0.0.
+    pub window_border_hover_padding: f32,
     /// Minimum window size
     pub window_min_size: [f32; 2],
     /// Alignment for title bar text.
@@ -92,16 +102,26 @@ pub struct Style {
     pub grab_rounding: f32,
     /// The size in pixels of the dead-zone around zero on logarithmic sliders that cross zero
     pub log_slider_deadzone: f32,
+    /// Thickness of border around images drawn with [`Image`](crate::Image).
+    pub image_border_size: f32,
     /// Rounding radius of upper corners of tabs.
     ///
     /// Set to 0.0 to have rectangular tabs.
     pub tab_rounding: f32,
     /// Thickness of border around tabs
     pub tab_border_size: f32,
+    /// Minimum width for close button to appear on the selected tab when hovered.
+    ///
+    /// `= -1.0`: always visible
+    /// `= 0.0`: visible when hovered
+    pub tab_close_button_min_width_selected: f32,
     /// Minimum width for close button to appear on an unselected tab when hovered.
     ///
-    /// `= 0.0`: always show when hovering
+    /// `= -1.0`: always visible
+    /// `= 0.0`: visible when hovered
     /// `= f32::MAX`: never show close button unless selected
+    ///
+    /// This is Dear ImGui's `TabCloseButtonMinWidthUnselected`.
     pub tab_min_width_for_close_button: f32,
 
     /// Thickness of tab-bar separator, which takes on the tab active color to denote focus.
@@ -212,6 +232,17 @@ impl Style {
         }
     }
 
+    /// Sets the border size of windows, child windows, popups, frames, tabs, and separators
+    /// with text at once, e.g. to turn all borders on or off.
+    pub fn set_all_borders(&mut self, size: f32) {
+        self.window_border_size = size;
+        self.child_border_size = size;
+        self.popup_border_size = size;
+        self.frame_border_size = size;
+        self.tab_border_size = size;
+        self.separator_text_border_size = size;
+    }
+
     /// Replaces current colors with a new, recommended style
     #[doc(alias = "StyleColors", alias = "StyleColorsDark")]
     pub fn use_dark_colors(&mut self) -> &mut Self {
@@ -240,801 +271,5024 @@ impl Style {
         }
         self
     }
-}
 
-impl Default for Style {
-    fn default() -> Self {
-        Self {
-            alpha: 1.0,
-            disabled_alpha: 0.6,
-            window_padding: [8.0, 8.0],
-            window_rounding: 0.0,
-            window_border_size: 1.0,
-            window_min_size: [32.0, 32.0],
-            window_title_align: [0.0, 0.5],
-            window_menu_button_position: Direction::Left,
-            child_rounding: 0.0,
-            child_border_size: 1.0,
-            popup_rounding: 0.0,
-            popup_border_size: 1.0,
-            frame_padding: [4.0, 3.0],
-            frame_rounding: 0.0,
-            frame_border_size: 0.0,
-            item_spacing: [8.0, 4.0],
-            item_inner_spacing: [4.0, 4.0],
-            cell_padding: [4.0, 2.0],
-            touch_extra_padding: [0.0, 0.0],
-            indent_spacing: 21.0,
-            columns_min_spacing: 6.0,
-            scrollbar_size: 14.0,
-            scrollbar_rounding: 9.0,
-            grab_min_size: 12.0,
-            grab_rounding: 0.0,
-            log_slider_deadzone: 4.0,
-            tab_rounding: 4.0,
-            tab_border_size: 0.0,
-            tab_min_width_for_close_button: 0.0,
-            tab_bar_border_size: 1.0,
-            tab_bar_overline_size: 2.0,
-            table_angled_headers_angle: 35.0 * (std::f32::consts::PI / 180.0),
-            table_angled_headers_text_align: [0.5, 0.0],
-            color_button_position: Direction::Right,
-            button_text_align: [0.5, 0.5],
-            selectable_text_align: [0.0, 0.0],
-            separator_text_border_size: 3.0,
-            separator_text_align: [0.0, 0.5],
-            separator_text_padding: [20.0, 3.0],
-            display_window_padding: [19.0, 19.0],
-            display_safe_area_padding: [3.0, 3.0],
-            #[cfg(feature = "docking")]
-            docking_separator_size: 2.0,
-            mouse_cursor_scale: 1.0,
-            anti_aliased_lines: true,
-            anti_aliased_lines_use_tex: true,
-            anti_aliased_fill: true,
-            curve_tessellation_tol: 1.25,
-            circle_tesselation_max_error: 0.3,
-            hover_stationary_delay: 0.15,
-            hover_delay_short: 0.15,
-            hover_delay_normal: 0.4,
-            hover_flags_for_tooltip_mouse: HoveredFlags::STATIONARY
-                | HoveredFlags::DELAY_SHORT
-                | HoveredFlags::ALLOW_WHEN_DISABLED,
-            hover_flags_for_tooltip_nav: HoveredFlags::NO_SHARED_DELAY
-                | HoveredFlags::DELAY_NORMAL
-                | HoveredFlags::ALLOW_WHEN_DISABLED,
-            colors: StyleColor::dark_colors(),
+    /// Replaces current colors with the [`StyleColor::photoshop_colors`] palette
+    pub fn use_photoshop_colors(&mut self) -> &mut Self {
+        self.colors = StyleColor::photoshop_colors();
+        self
+    }
+
+    /// Replaces current colors with the [`StyleColor::cherry_colors`] palette
+    pub fn use_cherry_colors(&mut self) -> &mut Self {
+        self.colors = StyleColor::cherry_colors();
+        self
+    }
+
+    /// Replaces current colors with the [`StyleColor::dracula_colors`] palette
+    pub fn use_dracula_colors(&mut self) -> &mut Self {
+        self.colors = StyleColor::dracula_colors();
+        self
+    }
+
+    /// Mirrors the alignment-related fields of the style for right-to-left languages.
+    ///
+    /// This flips the horizontal component of `window_title_align`, `button_text_align`,
+    /// `selectable_text_align` and `separator_text_align`, and swaps
+    /// `window_menu_button_position` and `color_button_position` between left and right.
+    ///
+    /// Whether the style is currently mirrored is inferred from `color_button_position`
+    /// (which is [`Direction::Right`] by default), so calling this twice with the same value
+    /// is a no-op. This is a best-effort mirror of the layout knobs; it does not affect how
+    /// text itself is shaped or laid out.
+    pub fn set_rtl(&mut self, rtl: bool) {
+        let is_rtl = self.color_button_position == Direction::Left;
+        if is_rtl == rtl {
+            return;
+        }
+
+        for align in [
+            &mut self.window_title_align,
+            &mut self.button_text_align,
+            &mut self.selectable_text_align,
+            &mut self.separator_text_align,
+        ] {
+            align[0] = 1.0 - align[0];
+        }
+
+        fn flip(dir: Direction) -> Direction {
+            match dir {
+                Direction::Left => Direction::Right,
+                Direction::Right => Direction::Left,
+                other => other,
+            }
         }
+        self.window_menu_button_position = flip(self.window_menu_button_position);
+        self.color_button_position = flip(self.color_button_position);
     }
-}
 
-impl Index<StyleColor> for Style {
-    type Output = [f32; 4];
-    #[inline]
-    fn index(&self, index: StyleColor) -> &[f32; 4] {
-        &self.colors[index as usize]
+    /// Returns every color packed into a `0xAABBGGRR` `u32`, see [`color_to_u32`].
+    pub fn colors_packed(&self) -> [u32; StyleColor::COUNT] {
+        self.colors.map(color_to_u32)
     }
-}
 
-impl IndexMut<StyleColor> for Style {
-    #[inline]
-    fn index_mut(&mut self, index: StyleColor) -> &mut [f32; 4] {
-        &mut self.colors[index as usize]
+    /// Sets every color from `0xAABBGGRR` `u32`s, see [`color_from_u32`].
+    pub fn set_colors_packed(&mut self, packed: &[u32; StyleColor::COUNT]) {
+        self.colors = packed.map(color_from_u32);
     }
-}
 
-/// A color identifier for styling.
-///
-/// Which color does what can sometimes be be unobvious. A good way to find a particular color is to use
-/// the [`crate::Ui::show_default_style_editor`] window, set a color to a very bright color, and explore the
-/// [`crate::Ui::show_demo_window`] until you spot it.
-///
-/// Take special note of [`StyleColor::dark_colors`], [`StyleColor::light_colors`], and [`StyleColor::classic_colors`],
-/// which can be used to get the color palettes ImGui uses.
-#[repr(u32)]
-#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
-#[non_exhaustive]
-pub enum StyleColor {
-    /// Default color of text througout application
-    Text = sys::ImGuiCol_Text,
-    /// Text in areas disabled e.g via [`crate::Ui::begin_disabled`]
-    TextDisabled = sys::ImGuiCol_TextDisabled,
-    /// Background of normal windows
-    WindowBg = sys::ImGuiCol_WindowBg,
-    /// Background of child windows
-    ChildBg = sys::ImGuiCol_ChildBg,
-    /// Background of popups, menus, tooltips windows
-    PopupBg = sys::ImGuiCol_PopupBg,
-    /// Border around windows, frames, etc
-    Border = sys::ImGuiCol_Border,
-    /// Used for a drop-shadow/emboss style effect wherever `Border` is used
-    BorderShadow = sys::ImGuiCol_BorderShadow,
-    /// Background of checkbox, radio button, plot, slider, text input
-    FrameBg = sys::ImGuiCol_FrameBg,
-    /// Same as `FrameBg` but when mouse is hovering over the widget
-    FrameBgHovered = sys::ImGuiCol_FrameBgHovered,
-    /// Same as `FrameBg` but when the mouse is active (e.g mouse is down)
-    FrameBgActive = sys::ImGuiCol_FrameBgActive,
-    /// Window title for inactive windows. Also used as the "docked window tab area" when docking is enabled.
-    TitleBg = sys::ImGuiCol_TitleBg,
-    /// Window title for active windows.
-    TitleBgActive = sys::ImGuiCol_TitleBgActive,
-    /// Color of a floating window when it is "rolled up"
-    TitleBgCollapsed = sys::ImGuiCol_TitleBgCollapsed,
-    /// Main menu bar background, see [`crate::Ui::main_menu_bar`]
-    MenuBarBg = sys::ImGuiCol_MenuBarBg,
-    /// Background area of scrollbar
-    ScrollbarBg = sys::ImGuiCol_ScrollbarBg,
-    /// Movable area of scollbar when "idle"
-    ScrollbarGrab = sys::ImGuiCol_ScrollbarGrab,
-    /// Moveable area of scrollbar when mouse is over it
-    ScrollbarGrabHovered = sys::ImGuiCol_ScrollbarGrabHovered,
-    /// Moveable area of scollbar when it is being clicked on
-    ScrollbarGrabActive = sys::ImGuiCol_ScrollbarGrabActive,
-    /// The color of the tick character inside the checkbox
-    CheckMark = sys::ImGuiCol_CheckMark,
-    /// Color of interactive handle inside various slider widgets
-    SliderGrab = sys::ImGuiCol_SliderGrab,
-    /// Interactive handle when being clicked on
-    SliderGrabActive = sys::ImGuiCol_SliderGrabActive,
-    /// Main frame color of default button
-    Button = sys::ImGuiCol_Button,
-    /// Button when mouse hovers over it
-    ButtonHovered = sys::ImGuiCol_ButtonHovered,
-    /// Button when mouse is down
-    ButtonActive = sys::ImGuiCol_ButtonActive,
-    /// Inactive color for header sections, such as [`crate::Ui::collapsing_header`]
-    Header = sys::ImGuiCol_Header,
-    /// As with `Header` but when hovered
-    HeaderHovered = sys::ImGuiCol_HeaderHovered,
-    /// As with `Header` but when mouse is down
-    HeaderActive = sys::ImGuiCol_HeaderActive,
-    /// Dividing line, e.g [`crate::Ui::separator`]
-    Separator = sys::ImGuiCol_Separator,
-    /// Dividing line when mouse hovered
-    SeparatorHovered = sys::ImGuiCol_SeparatorHovered,
-    /// Dividing line when mouse button down
-    SeparatorActive = sys::ImGuiCol_SeparatorActive,
-    /// Resize handle on windows
-    ResizeGrip = sys::ImGuiCol_ResizeGrip,
-    /// Resize handle when mouse hovered over handle
-    ResizeGripHovered = sys::ImGuiCol_ResizeGripHovered,
-    /// Resize handle when mouse button down
-    ResizeGripActive = sys::ImGuiCol_ResizeGripActive,
-    /// Hovered tab (applies regardless if tab is active, or is in the active window)
-    TabHovered = sys::ImGuiCol_TabHovered,
-    /// Inactive tab color. Applies to both tab widgets and docked windows
-    Tab = sys::ImGuiCol_Tab,
-    /// Color of currently selected tab
-    TabSelected = sys::ImGuiCol_TabSelected,
-    /// Tab horizontal overline, when tab-bar is focused & tab is selected
-    TabSelectedOverline = sys::ImGuiCol_TabSelectedOverline,
-    /// Non-selected, when in an unfocused window
-    TabDimmed = sys::ImGuiCol_TabDimmed,
-    /// Selected tab, in an unfocused window
-    TabDimmedSelected = sys::ImGuiCol_TabDimmedSelected,
-    /// Non-selected, when in an unfocused window
-    TabDimmedSelectedOverline = sys::ImGuiCol_TabDimmedSelectedOverline,
+    /// Returns an iterator over every color of this style, paired with its [`StyleColor`].
+    pub fn iter_colors(&self) -> impl Iterator<Item = (StyleColor, &[f32; 4])> {
+        StyleColor::VARIANTS.into_iter().zip(self.colors.iter())
+    }
 
-    /// Color of widget which appears when moving windows around, allowing splitting/etc of dock areas
-    #[cfg(feature = "docking")]
-    DockingPreview = sys::ImGuiCol_DockingPreview,
-    /// Colour when black area is present in docking setup (e.g while dragging a window away from a split area, leaving it temporarily empty)
-    #[cfg(feature = "docking")]
-    DockingEmptyBg = sys::ImGuiCol_DockingEmptyBg,
+    /// Returns an iterator over mutable references to every color of this style, paired with
+    /// its [`StyleColor`].
+    ///
+    /// ```
+    /// # use imgui::*;
+    /// let mut style = Style::default();
+    /// for (_, color) in style.iter_colors_mut() {
+    ///     color[3] *= 0.5;
+    /// }
+    /// ```
+    pub fn iter_colors_mut(&mut self) -> impl Iterator<Item = (StyleColor, &mut [f32; 4])> {
+        StyleColor::VARIANTS.into_iter().zip(self.colors.iter_mut())
+    }
 
-    /// Lines in [`crate::Ui::plot_lines`]
-    PlotLines = sys::ImGuiCol_PlotLines,
-    /// `PlotLines` when hovered
-    PlotLinesHovered = sys::ImGuiCol_PlotLinesHovered,
-    /// Used for [`crate::Ui::plot_histogram`]
-    PlotHistogram = sys::ImGuiCol_PlotHistogram,
-    /// `PlotHistogram` when hovered
-    PlotHistogramHovered = sys::ImGuiCol_PlotHistogramHovered,
+    /// Returns the `[normal, hovered, active]` colors of an interactive color family.
+    ///
+    /// `base` may be any member of the family, e.g. both [`StyleColor::Button`] and
+    /// [`StyleColor::ButtonHovered`] return the three button colors. Returns `None` for colors
+    /// which don't have hovered/active states.
+    pub fn interactive_colors(&self, base: StyleColor) -> Option<[[f32; 4]; 3]> {
+        base.interactive_family()
+            .map(|family| family.map(|color| self[color]))
+    }
 
-    /// Background color of header rows in table widget
-    TableHeaderBg = sys::ImGuiCol_TableHeaderBg,
-    /// Main border color for table, used around whole table and around header cells
-    TableBorderStrong = sys::ImGuiCol_TableBorderStrong,
-    /// Used within border to separate cells
-    TableBorderLight = sys::ImGuiCol_TableBorderLight,
-    /// Background of cells in table
-    TableRowBg = sys::ImGuiCol_TableRowBg,
-    /// Used for alternating row colors, if enabled by `TableFlags::ROW_BG`
-    TableRowBgAlt = sys::ImGuiCol_TableRowBgAlt,
+    /// Returns the name and value of every field in declaration order, e.g. to print the
+    /// whole style in a debug overlay.
+    ///
+    /// `colors` is flattened into one [`StyleFieldValue::Color`] entry per color, named by
+    /// [`StyleColor::name`], after all other fields.
+    pub fn dump_fields(&self) -> Vec<(&'static str, StyleFieldValue)> {
+        let mut fields = self.field_values();
+        fields.extend(
+            self.iter_colors()
+                .map(|(color, &value)| (color.name(), StyleFieldValue::Color(value))),
+        );
+        fields
+    }
 
-    /// Hyperlink color
-    TextLink = sys::ImGuiCol_TextLink,
-    /// The highlight color used for selection in text inputs
-    TextSelectedBg = sys::ImGuiCol_TextSelectedBg,
+    /// Returns every field and color of this style which differs from `base`.
+    ///
+    /// Floats are compared exactly; see [`diff_with_epsilon`](Self::diff_with_epsilon) to
+    /// ignore tiny differences.
+    pub fn diff(&self, base: &Style) -> StyleDiff {
+        self.diff_with_epsilon(base, 0.0)
+    }
 
-    /// Used for drag-and-drop system
-    DragDropTarget = sys::ImGuiCol_DragDropTarget,
-    /// Color of keyboard/gamepad navigation cursor/rectangle, when visible
-    NavCursor = sys::ImGuiCol_NavCursor,
-    /// Highlight window when using CTRL+TAB
-    NavWindowingHighlight = sys::ImGuiCol_NavWindowingHighlight,
-    /// Darken/colorize entire screen behind the CTRL+TAB window list, when active
-    NavWindowingDimBg = sys::ImGuiCol_NavWindowingDimBg,
-    /// Darken/colorize entire screen behind a modal window, when one is active
-    ModalWindowDimBg = sys::ImGuiCol_ModalWindowDimBg,
-}
+    /// Like [`diff`](Self::diff), but treats floats (including each component of pairs and
+    /// colors) as equal when they differ by at most `epsilon`.
+    pub fn diff_with_epsilon(&self, base: &Style, epsilon: f32) -> StyleDiff {
+        let mut entries = Vec::new();
+        for ((name, base), (_, value)) in base.field_values().into_iter().zip(self.field_values()) {
+            if !base.approx_eq(&value, epsilon) {
+                entries.push(StyleDiffEntry {
+                    key: StyleKey::Field(name),
+                    base,
+                    value,
+                });
+            }
+        }
+        for color in StyleColor::VARIANTS {
+            let (base, value) = (base[color].into(), self[color].into());
+            if !StyleFieldValue::approx_eq(&base, &value, epsilon) {
+                entries.push(StyleDiffEntry {
+                    key: StyleKey::Color(color),
+                    base,
+                    value,
+                });
+            }
+        }
+        StyleDiff { entries }
+    }
+
+    /// Creates a style whose palette is expanded from a small set of design tokens.
+    ///
+    /// All non-color fields are taken from [`Style::default`]. See [`ThemeTokens`] for how each
+    /// token maps onto [`StyleColor`]s.
+    pub fn from_tokens(tokens: ThemeTokens) -> Style {
+        let ThemeTokens {
+            primary,
+            surface,
+            on_surface,
+            error,
+        } = tokens;
+        let with_alpha = |[r, g, b, _]: [f32; 4], a: f32| [r, g, b, a];
+        let mix = |a: [f32; 4], b: [f32; 4], t: f32| with_alpha(lerp(a, b, t), 1.0);
+
+        let mut colors = StyleColor::dark_colors();
+        let mut set = |color: StyleColor, value: [f32; 4]| colors[color as usize] = value;
+
+        // surfaces
+        set(StyleColor::WindowBg, surface);
+        set(StyleColor::ChildBg, with_alpha(surface, 0.0));
+        set(StyleColor::PopupBg, mix(surface, on_surface, 0.04));
+        set(StyleColor::MenuBarBg, mix(surface, on_surface, 0.08));
+        set(StyleColor::TitleBg, mix(surface, on_surface, 0.02));
+        set(StyleColor::TitleBgCollapsed, with_alpha(surface, 0.51));
+        set(StyleColor::ScrollbarBg, with_alpha(surface, 0.53));
+        set(StyleColor::TableHeaderBg, mix(surface, on_surface, 0.12));
+        set(
+            StyleColor::FrameBg,
+            with_alpha(mix(surface, primary, 0.25), 0.54),
+        );
+
+        // content drawn on top of surfaces
+        set(StyleColor::Text, on_surface);
+        set(StyleColor::TextDisabled, mix(on_surface, surface, 0.5));
+        set(StyleColor::Border, with_alpha(on_surface, 0.25));
+        set(StyleColor::Separator, with_alpha(on_surface, 0.25));
+        set(StyleColor::ScrollbarGrab, mix(surface, on_surface, 0.25));
+        set(
+            StyleColor::ScrollbarGrabHovered,
+            mix(surface, on_surface, 0.35),
+        );
+        set(
+            StyleColor::ScrollbarGrabActive,
+            mix(surface, on_surface, 0.45),
+        );
+        set(
+            StyleColor::TableBorderStrong,
+            mix(surface, on_surface, 0.25),
+        );
+        set(StyleColor::TableBorderLight, mix(surface, on_surface, 0.15));
+        set(StyleColor::TableRowBgAlt, with_alpha(on_surface, 0.06));
+        set(StyleColor::PlotLines, mix(on_surface, surface, 0.4));
+
+        // interactive accents
+        set(StyleColor::TitleBgActive, mix(surface, primary, 0.5));
+        set(StyleColor::FrameBgHovered, with_alpha(primary, 0.40));
+        set(StyleColor::FrameBgActive, with_alpha(primary, 0.67));
+        set(StyleColor::CheckMark, with_alpha(primary, 1.0));
+        set(StyleColor::SliderGrab, mix(primary, surface, 0.1));
+        set(StyleColor::SliderGrabActive, with_alpha(primary, 1.0));
+        set(StyleColor::Button, with_alpha(primary, 0.40));
+        set(StyleColor::ButtonHovered, with_alpha(primary, 1.0));
+        set(StyleColor::ButtonActive, mix(primary, surface, 0.2));
+        set(StyleColor::Header, with_alpha(primary, 0.31));
+        set(StyleColor::HeaderHovered, with_alpha(primary, 0.80));
+        set(StyleColor::HeaderActive, with_alpha(primary, 1.0));
+        set(StyleColor::SeparatorHovered, with_alpha(primary, 0.78));
+        set(StyleColor::SeparatorActive, with_alpha(primary, 1.0));
+        set(StyleColor::ResizeGrip, with_alpha(primary, 0.20));
+        set(StyleColor::ResizeGripHovered, with_alpha(primary, 0.67));
+        set(StyleColor::ResizeGripActive, with_alpha(primary, 0.95));
+        set(StyleColor::TextLink, with_alpha(primary, 1.0));
+        set(StyleColor::TextSelectedBg, with_alpha(primary, 0.35));
+        set(StyleColor::NavCursor, with_alpha(primary, 1.0));
+
+        // errors
+        set(StyleColor::PlotLinesHovered, error);
+        set(StyleColor::PlotHistogramHovered, error);
+
+        // tabs are derived from the colors above, like in `dark_colors`
+        let header = colors[StyleColor::Header as usize];
+        let header_active = colors[StyleColor::HeaderActive as usize];
+        let title_bg = colors[StyleColor::TitleBg as usize];
+        let title_bg_active = colors[StyleColor::TitleBgActive as usize];
+        colors[StyleColor::TabHovered as usize] = colors[StyleColor::HeaderHovered as usize];
+        colors[StyleColor::Tab as usize] = lerp(header, title_bg_active, 0.80);
+        colors[StyleColor::TabSelected as usize] = lerp(header_active, title_bg_active, 0.60);
+        colors[StyleColor::TabSelectedOverline as usize] = header_active;
+        colors[StyleColor::TabDimmed as usize] =
+            lerp(colors[StyleColor::Tab as usize], title_bg, 0.80);
+        colors[StyleColor::TabDimmedSelected as usize] =
+            lerp(colors[StyleColor::TabSelected as usize], title_bg, 0.40);
 
-impl StyleColor {
-    /// All possible `StyleColor` variants
-    pub const VARIANTS: [StyleColor; StyleColor::COUNT] = [
-        StyleColor::Text,
-        StyleColor::TextDisabled,
-        StyleColor::WindowBg,
-        StyleColor::ChildBg,
-        StyleColor::PopupBg,
-        StyleColor::Border,
-        StyleColor::BorderShadow,
-        StyleColor::FrameBg,
-        StyleColor::FrameBgHovered,
-        StyleColor::FrameBgActive,
-        StyleColor::TitleBg,
-        StyleColor::TitleBgActive,
-        StyleColor::TitleBgCollapsed,
-        StyleColor::MenuBarBg,
-        StyleColor::ScrollbarBg,
-        StyleColor::ScrollbarGrab,
-        StyleColor::ScrollbarGrabHovered,
-        StyleColor::ScrollbarGrabActive,
-        StyleColor::CheckMark,
-        StyleColor::SliderGrab,
-        StyleColor::SliderGrabActive,
-        StyleColor::Button,
-        StyleColor::ButtonHovered,
-        StyleColor::ButtonActive,
-        StyleColor::Header,
-        StyleColor::HeaderHovered,
-        StyleColor::HeaderActive,
-        StyleColor::Separator,
-        StyleColor::SeparatorHovered,
-        StyleColor::SeparatorActive,
-        StyleColor::ResizeGrip,
-        StyleColor::ResizeGripHovered,
-        StyleColor::ResizeGripActive,
-        StyleColor::TabHovered,
-        StyleColor::Tab,
-        StyleColor::TabSelected,
-        StyleColor::TabSelectedOverline,
-        StyleColor::TabDimmed,
-        StyleColor::TabDimmedSelected,
-        StyleColor::TabDimmedSelectedOverline,
-        #[cfg(feature = "docking")]
-        StyleColor::DockingPreview,
         #[cfg(feature = "docking")]
-        StyleColor::DockingEmptyBg,
-        StyleColor::PlotLines,
-        StyleColor::PlotLinesHovered,
-        StyleColor::PlotHistogram,
-        StyleColor::PlotHistogramHovered,
-        StyleColor::TableHeaderBg,
-        StyleColor::TableBorderStrong,
-        StyleColor::TableBorderLight,
-        StyleColor::TableRowBg,
-        StyleColor::TableRowBgAlt,
-        StyleColor::TextLink,
-        StyleColor::TextSelectedBg,
-        StyleColor::DragDropTarget,
-        StyleColor::NavCursor,
-        StyleColor::NavWindowingHighlight,
-        StyleColor::NavWindowingDimBg,
-        StyleColor::ModalWindowDimBg,
-    ];
-    /// Total count of `StyleColor` variants
-    pub const COUNT: usize = sys::ImGuiCol_COUNT as usize;
+        {
+            colors[StyleColor::DockingPreview as usize] = with_alpha(header_active, 0.7);
+            colors[StyleColor::DockingEmptyBg as usize] = mix(surface, on_surface, 0.15);
+        }
 
-    /// Returns the name of the Style Color.
-    // Note: we do this in Rust (where we have better promises of enums
-    // being of the right type) than in C++ to avoid the FFI. We confirm in
-    // Unit Tests that we are accurate.
-    pub fn name(&self) -> &'static str {
-        match self {
-            StyleColor::Text => "Text",
-            StyleColor::TextDisabled => "TextDisabled",
-            StyleColor::WindowBg => "WindowBg",
-            StyleColor::ChildBg => "ChildBg",
-            StyleColor::PopupBg => "PopupBg",
-            StyleColor::Border => "Border",
-            StyleColor::BorderShadow => "BorderShadow",
-            StyleColor::FrameBg => "FrameBg",
-            StyleColor::FrameBgHovered => "FrameBgHovered",
-            StyleColor::FrameBgActive => "FrameBgActive",
-            StyleColor::TitleBg => "TitleBg",
-            StyleColor::TitleBgActive => "TitleBgActive",
-            StyleColor::TitleBgCollapsed => "TitleBgCollapsed",
-            StyleColor::MenuBarBg => "MenuBarBg",
-            StyleColor::ScrollbarBg => "ScrollbarBg",
-            StyleColor::ScrollbarGrab => "ScrollbarGrab",
-            StyleColor::ScrollbarGrabHovered => "ScrollbarGrabHovered",
-            StyleColor::ScrollbarGrabActive => "ScrollbarGrabActive",
-            StyleColor::CheckMark => "CheckMark",
-            StyleColor::SliderGrab => "SliderGrab",
-            StyleColor::SliderGrabActive => "SliderGrabActive",
-            StyleColor::Button => "Button",
-            StyleColor::ButtonHovered => "ButtonHovered",
-            StyleColor::ButtonActive => "ButtonActive",
-            StyleColor::Header => "Header",
-            StyleColor::HeaderHovered => "HeaderHovered",
-            StyleColor::HeaderActive => "HeaderActive",
-            StyleColor::Separator => "Separator",
-            StyleColor::SeparatorHovered => "SeparatorHovered",
-            StyleColor::SeparatorActive => "SeparatorActive",
-            StyleColor::ResizeGrip => "ResizeGrip",
-            StyleColor::ResizeGripHovered => "ResizeGripHovered",
-            StyleColor::ResizeGripActive => "ResizeGripActive",
-            StyleColor::Tab => "Tab",
-            StyleColor::TabHovered => "TabHovered",
-            StyleColor::TabSelected => "TabSelected",
-            StyleColor::TabDimmed => "TabDimmed",
-            StyleColor::TabDimmedSelected => "TabDimmedSelected",
-            StyleColor::PlotLines => "PlotLines",
-            StyleColor::PlotLinesHovered => "PlotLinesHovered",
-            StyleColor::PlotHistogram => "PlotHistogram",
-            StyleColor::PlotHistogramHovered => "PlotHistogramHovered",
-            StyleColor::TableHeaderBg => "TableHeaderBg",
-            StyleColor::TableBorderStrong => "TableBorderStrong",
-            StyleColor::TableBorderLight => "TableBorderLight",
-            StyleColor::TableRowBg => "TableRowBg",
-            StyleColor::TableRowBgAlt => "TableRowBgAlt",
-            StyleColor::TextSelectedBg => "TextSelectedBg",
-            StyleColor::DragDropTarget => "DragDropTarget",
-            StyleColor::NavCursor => "NavCursor",
-            StyleColor::NavWindowingHighlight => "NavWindowingHighlight",
-            StyleColor::NavWindowingDimBg => "NavWindowingDimBg",
-            StyleColor::ModalWindowDimBg => "ModalWindowDimBg",
-            #[cfg(feature = "docking")]
-            StyleColor::DockingPreview => "DockingPreview",
+        Style {
+            colors,
+            ..Style::default()
+        }
+    }
+
+    /// Approximates this style's palette as a set of design tokens.
+    ///
+    /// This reads back the colors [`Style::from_tokens`] writes each token to verbatim, so
+    /// `Style::from_tokens(tokens).to_tokens()` returns `tokens` (with `primary` fully opaque).
+    pub fn to_tokens(&self) -> ThemeTokens {
+        ThemeTokens {
+            primary: self[StyleColor::CheckMark],
+            surface: self[StyleColor::WindowBg],
+            on_surface: self[StyleColor::Text],
+            error: self[StyleColor::PlotLinesHovered],
+        }
+    }
+
+    /// Returns how the given color looks when drawn over [`StyleColor::WindowBg`].
+    ///
+    /// The color is composited with standard source-over blending onto the window background,
+    /// treated as opaque, so the result is always opaque.
+    pub fn composite_over_bg(&self, c: StyleColor) -> [f32; 4] {
+        let [r, g, b, _] = self[StyleColor::WindowBg];
+        blend_over(self[c], [r, g, b, 1.0])
+    }
+
+    /// Returns the background color of a panel nested `depth` child windows deep, with
+    /// [`StyleColor::ChildBg`] composited over [`StyleColor::WindowBg`] once per level.
+    ///
+    /// Depth 0 is the window itself. Translucent child backgrounds grow more opaque with every
+    /// level, approaching `ChildBg`.
+    pub fn nested_surface_color(&self, depth: u32) -> [f32; 4] {
+        let child = self[StyleColor::ChildBg];
+        (0..depth).fold(self[StyleColor::WindowBg], |bg, _| blend_over(child, bg))
+    }
+
+    /// Returns a colormap of `stops` evenly spaced colors, e.g. for heatmaps and other plots
+    /// which map values to colors.
+    pub fn plot_colormap(&self, kind: ColormapKind, stops: usize) -> Vec<[f32; 4]> {
+        // key colors of matplotlib's viridis colormap
+        const VIRIDIS: [[f32; 4]; 5] = [
+            [0.267, 0.005, 0.329, 1.0],
+            [0.231, 0.322, 0.545, 1.0],
+            [0.129, 0.569, 0.549, 1.0],
+            [0.369, 0.788, 0.384, 1.0],
+            [0.992, 0.906, 0.145, 1.0],
+        ];
+        let theme = [
+            self[StyleColor::PlotHistogram],
+            self[StyleColor::PlotHistogramHovered],
+        ];
+        let keys: &[[f32; 4]] = match kind {
+            ColormapKind::Theme => &theme,
+            ColormapKind::Viridis => &VIRIDIS,
+        };
+        (0..stops)
+            .map(|i| {
+                let t = if stops > 1 {
+                    i as f32 / (stops - 1) as f32
+                } else {
+                    0.0
+                };
+                let segments = keys.len() - 1;
+                let pos = t * segments as f32;
+                let segment = (pos as usize).min(segments - 1);
+                lerp(keys[segment], keys[segment + 1], pos - segment as f32)
+            })
+            .collect()
+    }
+
+    /// Returns the WCAG contrast ratio of [`StyleColor::Text`] against each common background
+    /// for which it is below 4.5, the minimum WCAG AA recommends for body text.
+    ///
+    /// Translucent backgrounds are composited over [`StyleColor::WindowBg`] first, see
+    /// [`contrast_ratio`].
+    pub fn check_text_contrast(&self) -> Vec<(StyleColor, f32)> {
+        const BACKGROUNDS: [StyleColor; 8] = [
+            StyleColor::WindowBg,
+            StyleColor::PopupBg,
+            StyleColor::FrameBg,
+            StyleColor::Button,
+            StyleColor::Header,
+            StyleColor::TitleBgActive,
+            StyleColor::MenuBarBg,
+            StyleColor::TableHeaderBg,
+        ];
+        BACKGROUNDS
+            .into_iter()
+            .map(|bg| {
+                let ratio = contrast_ratio(self[StyleColor::Text], self.composite_over_bg(bg));
+                (bg, ratio)
+            })
+            .filter(|&(_, ratio)| ratio < 4.5)
+            .collect()
+    }
+
+    /// Shifts every color to the hue `target_hue_deg` (in degrees), keeping its saturation,
+    /// lightness, and alpha.
+    ///
+    /// Working in HSL keeps the theme's contrast intact, unlike scaling RGB channels.
+    /// Near-gray colors such as text and borders have no meaningful hue and are left unchanged.
+    pub fn rehue(&mut self, target_hue_deg: f32) {
+        let hue = target_hue_deg.rem_euclid(360.0) / 360.0;
+        for color in self.colors.iter_mut() {
+            let [_, s, l] = rgb_to_hsl([color[0], color[1], color[2]]);
+            if s < 0.05 {
+                continue;
+            }
+            let [r, g, b] = hsl_to_rgb([hue, s, l]);
+            *color = [r, g, b, color[3]];
+        }
+    }
+
+    /// Returns the corner rounding Dear ImGui uses for the given kind of surface.
+    ///
+    /// Note that [`SurfaceKind::Tooltip`] uses `window_rounding`, not `popup_rounding`.
+    pub fn rounding_for(&self, kind: SurfaceKind) -> f32 {
+        match kind {
+            SurfaceKind::Window | SurfaceKind::Tooltip => self.window_rounding,
+            SurfaceKind::Child => self.child_rounding,
+            SurfaceKind::Popup => self.popup_rounding,
+            SurfaceKind::Frame => self.frame_rounding,
+            SurfaceKind::Scrollbar => self.scrollbar_rounding,
+            SurfaceKind::Grab => self.grab_rounding,
+            SurfaceKind::Tab => self.tab_rounding,
+        }
+    }
+
+    /// Assigns a single field or color from text, as typed into a scripting console.
+    ///
+    /// `key` is either a field name (e.g. `"frame_rounding"`) or `colors.` followed by a
+    /// color name (e.g. `"colors.Button"`). Values are written as:
+    ///
+    /// - numbers and booleans as usual: `"4.0"`, `"true"`;
+    /// - two-component fields as `"x,y"`: `"8,8"`;
+    /// - directions by name: `"Left"`;
+    /// - hovered flags as their integer bits;
+    /// - colors as `#` followed by hex digits, as accepted by [`color_from_hex`]:
+    ///   `"#3377ff"`.
+    ///
+    /// ```
+    /// # use imgui::*;
+    /// let mut style = Style::default();
+    /// style.apply_kv("window_padding", "8,8").unwrap();
+    /// style.apply_kv("colors.Button", "#3377ffcc").unwrap();
+    /// assert!(style.apply_kv("window_padding", "8").is_err());
+    /// ```
+    pub fn apply_kv(&mut self, key: &str, value: &str) -> Result<(), StyleKvError> {
+        let invalid = || StyleKvError::InvalidValue {
+            key: key.to_owned(),
+            value: value.to_owned(),
+        };
+        if let Some(name) = key.strip_prefix("colors.") {
+            let color = StyleColor::from_name(name)
+                .ok_or_else(|| StyleKvError::UnknownKey(key.to_owned()))?;
+            self[color] = parse_hex_color(value.trim()).ok_or_else(invalid)?;
+            return Ok(());
+        }
+        let current = self
+            .field_values()
+            .into_iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, current)| current)
+            .ok_or_else(|| StyleKvError::UnknownKey(key.to_owned()))?;
+        let value = parse_field_value(current, value).ok_or_else(invalid)?;
+        self.set_field_value(key, value);
+        Ok(())
+    }
+
+    /// Serializes this style as a `[Style]` ini section, one `key=value` line per field, for
+    /// storing alongside Dear ImGui's own settings. Read it back with
+    /// [`load_ini`](Self::load_ini).
+    ///
+    /// Keys are the field names, and colors are written as `Colors.` followed by the color
+    /// name, with comma separated components: `Colors.WindowBg=0.06,0.06,0.06,0.94`.
+    pub fn write_ini(&self) -> String {
+        let mut out = String::from("[Style]\n");
+        for (name, value) in self.field_values() {
+            let value = match value {
+                StyleFieldValue::Vec2([x, y]) => format!("{},{}", x, y),
+                StyleFieldValue::HoveredFlags(v) => v.bits().to_string(),
+                value => value.to_string(),
+            };
+            out.push_str(&format!("{}={}\n", name, value));
+        }
+        for (color, [r, g, b, a]) in self.iter_colors() {
+            out.push_str(&format!(
+                "Colors.{}={},{},{},{}\n",
+                color.name(),
+                r,
+                g,
+                b,
+                a
+            ));
+        }
+        out
+    }
+
+    /// Reads values written by [`write_ini`](Self::write_ini) into this style.
+    ///
+    /// Blank lines, section headers, and comments starting with `;` or `#` are ignored, as are
+    /// keys naming no field or color, so settings from newer or older versions still load.
+    /// On error the style is left unchanged.
+    pub fn load_ini(&mut self, ini: &str) -> Result<(), StyleIniError> {
+        let mut style = *self;
+        let fields = self.field_values();
+        for (i, line) in ini.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with([';', '#', '[']) {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(StyleIniError::MissingEquals { line: line_number })?;
+            let (key, value) = (key.trim(), value.trim());
+            let invalid = || StyleIniError::InvalidValue {
+                line: line_number,
+                key: key.to_owned(),
+                value: value.to_owned(),
+            };
+            if let Some(name) = key.strip_prefix("Colors.") {
+                if let Some(color) = StyleColor::from_name(name) {
+                    let components = value
+                        .split(',')
+                        .map(|c| c.trim().parse::<f32>())
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|_| invalid())?;
+                    style[color] = components.try_into().map_err(|_| invalid())?;
+                }
+            } else if let Some(&(name, current)) = fields.iter().find(|(name, _)| *name == key) {
+                let value = parse_field_value(current, value).ok_or_else(invalid)?;
+                style.set_field_value(name, value);
+            }
+        }
+        *self = style;
+        Ok(())
+    }
+
+    /// Returns the [`StyleColor::DragDropTarget`] color with a pulsing alpha, for custom drop
+    /// zones which should draw attention while a payload is dragged.
+    ///
+    /// `time` is in seconds, e.g. [`Ui::time`](crate::Ui::time). The alpha oscillates once
+    /// per second between half of the color's alpha and its full alpha. Dear ImGui itself
+    /// draws the drop target rectangle without animation.
+    pub fn drag_drop_target_animated(&self, time: f32) -> [f32; 4] {
+        let [r, g, b, a] = self[StyleColor::DragDropTarget];
+        let pulse = 0.5 + 0.5 * (time * std::f32::consts::TAU).cos();
+        [r, g, b, a * (0.5 + 0.5 * pulse)]
+    }
+
+    /// Blends every color toward `alert_palette` by `level` (clamped to 0.0..=1.0), e.g. to
+    /// redden the interface as a monitored metric worsens.
+    ///
+    /// The blend is applied to the current colors, so start from a copy of the unmodified
+    /// style every frame rather than calling this repeatedly on the same style.
+    pub fn interpolate_to_alert(
+        &mut self,
+        level: f32,
+        alert_palette: &[[f32; 4]; StyleColor::COUNT],
+    ) {
+        let t = level.clamp(0.0, 1.0);
+        for (color, &alert) in self.colors.iter_mut().zip(alert_palette) {
+            *color = lerp(*color, alert, t);
+        }
+    }
+
+    /// Returns a copy of this style with every value made finite and within the range Dear
+    /// ImGui accepts, e.g. before applying a style loaded from user input.
+    ///
+    /// Non-finite values are replaced by those of [`Style::default`], then:
+    ///
+    /// - alphas, alignments, and color components are clamped to 0.0..=1.0;
+    /// - `table_angled_headers_angle` is clamped to ±50 degrees, as in the style editor;
+    /// - `window_min_size` and `window_border_hover_padding` are made at least 1, and the
+    ///   tessellation tolerances and `mouse_cursor_scale` at least 0.1;
+    /// - the tab close button minimum widths are made at least -1.0 (always visible);
+    /// - every other size is made non-negative;
+    /// - `window_menu_button_position` and `color_button_position` are reset to their
+    ///   defaults if they aren't a direction Dear ImGui supports for them.
+    pub fn to_ffi_safe(&self) -> Style {
+        fn fix(name: &str, v: f32, fallback: f32) -> f32 {
+            let v = if v.is_finite() { v } else { fallback };
+            match name {
+                "alpha" | "disabled_alpha" => v.clamp(0.0, 1.0),
+                _ if name.ends_with("_align") => v.clamp(0.0, 1.0),
+                "table_angled_headers_angle" => {
+                    let max = 50f32.to_radians();
+                    v.clamp(-max, max)
+                }
+                "window_min_size" | "window_border_hover_padding" => v.max(1.0),
+                "tab_close_button_min_width_selected" | "tab_min_width_for_close_button" => {
+                    v.max(-1.0)
+                }
+                "curve_tessellation_tol"
+                | "circle_tesselation_max_error"
+                | "mouse_cursor_scale" => v.max(0.1),
+                _ => v.max(0.0),
+            }
+        }
+
+        let default = Style::default();
+        let mut out = *self;
+        for ((name, value), (_, fallback)) in
+            self.field_values().into_iter().zip(default.field_values())
+        {
+            let value = match (value, fallback) {
+                (StyleFieldValue::F32(v), StyleFieldValue::F32(d)) => {
+                    StyleFieldValue::F32(fix(name, v, d))
+                }
+                (StyleFieldValue::Vec2(v), StyleFieldValue::Vec2(d)) => {
+                    StyleFieldValue::Vec2(std::array::from_fn(|i| fix(name, v[i], d[i])))
+                }
+                _ => continue,
+            };
+            out.set_field_value(name, value);
+        }
+        for (color, fallback) in out.colors.iter_mut().zip(default.colors) {
+            for (c, d) in color.iter_mut().zip(fallback) {
+                *c = if c.is_finite() { c.clamp(0.0, 1.0) } else { d };
+            }
+        }
+        if matches!(
+            out.window_menu_button_position,
+            Direction::Up | Direction::Down
+        ) {
+            out.window_menu_button_position = default.window_menu_button_position;
+        }
+        if !matches!(
+            out.color_button_position,
+            Direction::Left | Direction::Right
+        ) {
+            out.color_button_position = default.color_button_position;
+        }
+        out
+    }
+
+    /// Copies the bytes of this style into a `sys::ImGuiStyle`, as [`RawCast`] reinterprets
+    /// them, and reads it back one named field at a time, e.g. to assert at runtime that a style is unchanged by the
+    /// conversion.
+    ///
+    /// A field which sits at a different offset than its `sys::ImGuiStyle` counterpart comes
+    /// back with the value of whichever field shares its bytes, so the result no longer
+    /// compares equal. Only the bytes both structs have in common are copied, so when their
+    /// sizes disagree the missing fields come back zeroed instead of being read out of bounds.
+    pub fn roundtrip_through_sys(&self) -> Style {
+        fn direction(dir: sys::ImGuiDir) -> Direction {
+            match dir {
+                sys::ImGuiDir_Left => Direction::Left,
+                sys::ImGuiDir_Right => Direction::Right,
+                sys::ImGuiDir_Up => Direction::Up,
+                sys::ImGuiDir_Down => Direction::Down,
+                _ => Direction::None,
+            }
+        }
+
+        let len = mem::size_of::<Style>().min(mem::size_of::<sys::ImGuiStyle>());
+        let mut raw: sys::ImGuiStyle = unsafe { mem::zeroed() };
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self as *const Style as *const u8,
+                &mut raw as *mut sys::ImGuiStyle as *mut u8,
+                len,
+            );
+        }
+        Style {
+            alpha: raw.Alpha,
+            disabled_alpha: raw.DisabledAlpha,
+            window_padding: raw.WindowPadding.into(),
+            window_rounding: raw.WindowRounding,
+            window_border_size: raw.WindowBorderSize,
+            window_border_hover_padding: raw.WindowBorderHoverPadding,
+            window_min_size: raw.WindowMinSize.into(),
+            window_title_align: raw.WindowTitleAlign.into(),
+            window_menu_button_position: direction(raw.WindowMenuButtonPosition),
+            child_rounding: raw.ChildRounding,
+            child_border_size: raw.ChildBorderSize,
+            popup_rounding: raw.PopupRounding,
+            popup_border_size: raw.PopupBorderSize,
+            frame_padding: raw.FramePadding.into(),
+            frame_rounding: raw.FrameRounding,
+            frame_border_size: raw.FrameBorderSize,
+            item_spacing: raw.ItemSpacing.into(),
+            item_inner_spacing: raw.ItemInnerSpacing.into(),
+            cell_padding: raw.CellPadding.into(),
+            touch_extra_padding: raw.TouchExtraPadding.into(),
+            indent_spacing: raw.IndentSpacing,
+            columns_min_spacing: raw.ColumnsMinSpacing,
+            scrollbar_size: raw.ScrollbarSize,
+            scrollbar_rounding: raw.ScrollbarRounding,
+            grab_min_size: raw.GrabMinSize,
+            grab_rounding: raw.GrabRounding,
+            log_slider_deadzone: raw.LogSliderDeadzone,
+            image_border_size: raw.ImageBorderSize,
+            tab_rounding: raw.TabRounding,
+            tab_border_size: raw.TabBorderSize,
+            tab_close_button_min_width_selected: raw.TabCloseButtonMinWidthSelected,
+            tab_min_width_for_close_button: raw.TabCloseButtonMinWidthUnselected,
+            tab_bar_border_size: raw.TabBarBorderSize,
+            tab_bar_overline_size: raw.TabBarOverlineSize,
+            table_angled_headers_angle: raw.TableAngledHeadersAngle,
+            table_angled_headers_text_align: raw.TableAngledHeadersTextAlign.into(),
+            color_button_position: direction(raw.ColorButtonPosition),
+            button_text_align: raw.ButtonTextAlign.into(),
+            selectable_text_align: raw.SelectableTextAlign.into(),
+            separator_text_border_size: raw.SeparatorTextBorderSize,
+            separator_text_align: raw.SeparatorTextAlign.into(),
+            separator_text_padding: raw.SeparatorTextPadding.into(),
+            display_window_padding: raw.DisplayWindowPadding.into(),
+            display_safe_area_padding: raw.DisplaySafeAreaPadding.into(),
             #[cfg(feature = "docking")]
-            StyleColor::DockingEmptyBg => "DockingEmptyBg",
-            StyleColor::TabSelectedOverline => "TabSelectedOverline",
-            StyleColor::TabDimmedSelectedOverline => "TabDimmedSelectedOverline",
-            StyleColor::TextLink => "TextLink",
+            docking_separator_size: raw.DockingSeparatorSize,
+            mouse_cursor_scale: raw.MouseCursorScale,
+            anti_aliased_lines: raw.AntiAliasedLines,
+            anti_aliased_lines_use_tex: raw.AntiAliasedLinesUseTex,
+            anti_aliased_fill: raw.AntiAliasedFill,
+            curve_tessellation_tol: raw.CurveTessellationTol,
+            circle_tesselation_max_error: raw.CircleTessellationMaxError,
+            colors: raw.Colors.map(|color| [color.x, color.y, color.z, color.w]),
+            hover_stationary_delay: raw.HoverStationaryDelay,
+            hover_delay_short: raw.HoverDelayShort,
+            hover_delay_normal: raw.HoverDelayNormal,
+            hover_flags_for_tooltip_mouse: HoveredFlags::from_bits_truncate(
+                raw.HoverFlagsForTooltipMouse as u32,
+            ),
+            hover_flags_for_tooltip_nav: HoveredFlags::from_bits_truncate(
+                raw.HoverFlagsForTooltipNav as u32,
+            ),
         }
     }
 
-    /// Returns the "Dark" style colors for ImGui as an array.
+    /// Sets [`StyleColor::NavCursor`] and [`StyleColor::NavWindowingHighlight`] from the
+    /// accent color, [`StyleColor::HeaderActive`], so keyboard navigation matches the theme.
     ///
-    /// You can set this output to [`Style::colors`] to change the style palette.
-    pub fn dark_colors() -> [[f32; 4]; StyleColor::COUNT] {
-        let mut colors = [Default::default(); StyleColor::COUNT];
+    /// To keep them in step automatically, enable
+    /// [`StyleExtras::link_nav_to_accent`] instead.
+    pub fn derive_nav_from_accent(&mut self) {
+        let [cursor, windowing] = nav_colors_from_accent(self[StyleColor::HeaderActive]);
+        self[StyleColor::NavCursor] = cursor;
+        self[StyleColor::NavWindowingHighlight] = windowing;
+    }
 
-        colors[Self::Text as usize] = [1.00, 1.00, 1.00, 1.00];
-        colors[Self::TextDisabled as usize] = [0.50, 0.50, 0.50, 1.00];
-        colors[Self::WindowBg as usize] = [0.06, 0.06, 0.06, 0.94];
-        colors[Self::ChildBg as usize] = [0.00, 0.00, 0.00, 0.00];
-        colors[Self::PopupBg as usize] = [0.08, 0.08, 0.08, 0.94];
-        colors[Self::Border as usize] = [0.43, 0.43, 0.50, 0.50];
-        colors[Self::BorderShadow as usize] = [0.00, 0.00, 0.00, 0.00];
-        colors[Self::FrameBg as usize] = [0.16, 0.29, 0.48, 0.54];
-        colors[Self::FrameBgHovered as usize] = [0.26, 0.59, 0.98, 0.40];
-        colors[Self::FrameBgActive as usize] = [0.26, 0.59, 0.98, 0.67];
-        colors[Self::TitleBg as usize] = [0.04, 0.04, 0.04, 1.00];
-        colors[Self::TitleBgActive as usize] = [0.16, 0.29, 0.48, 1.00];
-        colors[Self::TitleBgCollapsed as usize] = [0.00, 0.00, 0.00, 0.51];
-        colors[Self::MenuBarBg as usize] = [0.14, 0.14, 0.14, 1.00];
-        colors[Self::ScrollbarBg as usize] = [0.02, 0.02, 0.02, 0.53];
-        colors[Self::ScrollbarGrab as usize] = [0.31, 0.31, 0.31, 1.00];
-        colors[Self::ScrollbarGrabHovered as usize] = [0.41, 0.41, 0.41, 1.00];
-        colors[Self::ScrollbarGrabActive as usize] = [0.51, 0.51, 0.51, 1.00];
-        colors[Self::CheckMark as usize] = [0.26, 0.59, 0.98, 1.00];
-        colors[Self::SliderGrab as usize] = [0.24, 0.52, 0.88, 1.00];
-        colors[Self::SliderGrabActive as usize] = [0.26, 0.59, 0.98, 1.00];
-        colors[Self::Button as usize] = [0.26, 0.59, 0.98, 0.40];
-        colors[Self::ButtonHovered as usize] = [0.26, 0.59, 0.98, 1.00];
-        colors[Self::ButtonActive as usize] = [0.06, 0.53, 0.98, 1.00];
-        colors[Self::Header as usize] = [0.26, 0.59, 0.98, 0.31];
-        colors[Self::HeaderHovered as usize] = [0.26, 0.59, 0.98, 0.80];
-        colors[Self::HeaderActive as usize] = [0.26, 0.59, 0.98, 1.00];
-        colors[Self::Separator as usize] = colors[Self::Border as usize];
-        colors[Self::SeparatorHovered as usize] = [0.10, 0.40, 0.75, 0.78];
-        colors[Self::SeparatorActive as usize] = [0.10, 0.40, 0.75, 1.00];
-        colors[Self::ResizeGrip as usize] = [0.26, 0.59, 0.98, 0.20];
-        colors[Self::ResizeGripHovered as usize] = [0.26, 0.59, 0.98, 0.67];
-        colors[Self::ResizeGripActive as usize] = [0.26, 0.59, 0.98, 0.95];
-        colors[Self::TabHovered as usize] = colors[Self::HeaderHovered as usize];
-        colors[Self::Tab as usize] = lerp(
-            colors[Self::Header as usize],
-            colors[Self::TitleBgActive as usize],
-            0.80,
+    /// Returns a muted version of a color, with its saturation reduced by `amount` (0.0 keeps
+    /// the color, 1.0 turns it gray) while keeping its lightness and alpha.
+    pub fn muted(&self, c: StyleColor, amount: f32) -> [f32; 4] {
+        let [r, g, b, a] = self[c];
+        let [h, s, l] = rgb_to_hsl([r, g, b]);
+        let [r, g, b] = hsl_to_rgb([h, s * (1.0 - amount.clamp(0.0, 1.0)), l]);
+        [r, g, b, a]
+    }
+
+    /// Converts the RGB channels of every color from sRGB to linear space, leaving alpha
+    /// untouched.
+    ///
+    /// Dear ImGui's palettes are authored in sRGB. Call this once after setting a palette if
+    /// your renderer blends in linear space; calling it again converts the colors twice.
+    pub fn convert_colors_srgb_to_linear(&mut self) {
+        for color in &mut self.colors {
+            for c in &mut color[..3] {
+                *c = srgb_to_linear(*c);
+            }
+        }
+    }
+
+    /// Converts the RGB channels of every color from linear to sRGB space, leaving alpha
+    /// untouched. This is the inverse of
+    /// [`convert_colors_srgb_to_linear`](Self::convert_colors_srgb_to_linear).
+    pub fn convert_colors_linear_to_srgb(&mut self) {
+        for color in &mut self.colors {
+            for c in &mut color[..3] {
+                *c = linear_to_srgb(*c);
+            }
+        }
+    }
+
+    /// Serializes this style as TOML, with each field preceded by a comment describing it,
+    /// for theme files meant to be edited by hand.
+    ///
+    /// Colors are written to a `[colors]` table keyed by [`StyleColor::name`].
+    pub fn to_commented_toml(&self) -> String {
+        fn number(out: &mut String, v: f32) {
+            if v.is_nan() {
+                out.push_str("nan");
+            } else if v.is_infinite() {
+                out.push_str(if v > 0.0 { "inf" } else { "-inf" });
+            } else {
+                // `Debug` always includes a decimal point, so TOML reads the value as a float
+                out.push_str(&format!("{:?}", v));
+            }
+        }
+        fn array(out: &mut String, values: &[f32]) {
+            out.push('[');
+            for (i, &v) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                number(out, v);
+            }
+            out.push(']');
+        }
+
+        let mut out = String::new();
+        for ((name, value), (_, doc)) in self.field_values().into_iter().zip(Self::FIELD_DOCS) {
+            out.push_str(&format!("# {}\n{} = ", doc, name));
+            match value {
+                StyleFieldValue::F32(v) => number(&mut out, v),
+                StyleFieldValue::Vec2(v) => array(&mut out, &v),
+                StyleFieldValue::Bool(v) => out.push_str(&v.to_string()),
+                StyleFieldValue::Direction(v) => out.push_str(&format!("\"{:?}\"", v)),
+                StyleFieldValue::HoveredFlags(v) => out.push_str(&format!("\"{:?}\"", v)),
+                StyleFieldValue::Color(v) => array(&mut out, &v),
+            }
+            out.push('\n');
+        }
+        out.push_str("\n# Style colors, as [r, g, b, a]\n[colors]\n");
+        for (color, value) in self.iter_colors() {
+            out.push_str(&format!("{} = ", color.name()));
+            array(&mut out, value);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Generates C++ code which applies this style's differences from `base` to Dear ImGui's
+    /// current style, e.g. to share a theme tweaked from Rust with a C++ project.
+    ///
+    /// ```
+    /// # use imgui::*;
+    /// let mut style = Style::default();
+    /// style.frame_rounding = 4.0;
+    /// let cpp = style.to_cpp_source(&Style::default());
+    /// assert_eq!(cpp, "ImGuiStyle& style = ImGui::GetStyle();\nstyle.FrameRounding = 4.0f;\n");
+    /// ```
+    pub fn to_cpp_source(&self, base: &Style) -> String {
+        fn number(v: f32) -> String {
+            if v.is_nan() {
+                "NAN".to_string()
+            } else if v.is_infinite() {
+                (if v > 0.0 { "INFINITY" } else { "-INFINITY" }).to_string()
+            } else {
+                // `Debug` always includes a decimal point, which C++ needs before the suffix
+                format!("{:?}f", v)
+            }
+        }
+        // the C++ name of a field, which is its Rust name in PascalCase apart from a few
+        // fields renamed on either side
+        fn field_name(name: &str) -> String {
+            match name {
+                "tab_min_width_for_close_button" => "TabCloseButtonMinWidthUnselected".to_string(),
+                "circle_tesselation_max_error" => "CircleTessellationMaxError".to_string(),
+                _ => name
+                    .split('_')
+                    .map(|word| {
+                        let mut chars = word.chars();
+                        chars.next().map_or_else(String::new, |first| {
+                            first.to_ascii_uppercase().to_string() + chars.as_str()
+                        })
+                    })
+                    .collect(),
+            }
+        }
+
+        let mut out = String::from("ImGuiStyle& style = ImGui::GetStyle();\n");
+        for entry in self.diff(base).entries() {
+            let lhs = match entry.key {
+                StyleKey::Field(name) => format!("style.{}", field_name(name)),
+                StyleKey::Color(color) => format!("style.Colors[ImGuiCol_{}]", color.name()),
+            };
+            let rhs = match entry.value {
+                StyleFieldValue::F32(v) => number(v),
+                StyleFieldValue::Vec2([x, y]) => format!("ImVec2({}, {})", number(x), number(y)),
+                StyleFieldValue::Bool(v) => v.to_string(),
+                StyleFieldValue::Direction(v) => format!("ImGuiDir_{:?}", v),
+                StyleFieldValue::HoveredFlags(v) => format!("{} /* {:?} */", v.bits(), v),
+                StyleFieldValue::Color([r, g, b, a]) => format!(
+                    "ImVec4({}, {}, {}, {})",
+                    number(r),
+                    number(g),
+                    number(b),
+                    number(a)
+                ),
+            };
+            out.push_str(&format!("{} = {};\n", lhs, rhs));
+        }
+        out
+    }
+
+    /// Returns the [`StyleColor::WindowBg`] color as actually drawn, with its alpha multiplied
+    /// by the global [`alpha`](Self::alpha), e.g. to blend a custom background behind windows.
+    pub fn window_bg_effective(&self) -> [f32; 4] {
+        let [r, g, b, a] = self[StyleColor::WindowBg];
+        [r, g, b, a * self.alpha]
+    }
+
+    /// Returns the color of disabled text.
+    ///
+    /// Custom widgets should use this rather than fading [`StyleColor::Text`], so they match
+    /// the built-in widgets.
+    pub fn disabled_text_color(&self) -> [f32; 4] {
+        self[StyleColor::TextDisabled]
+    }
+
+    /// Interpolates between two styles, e.g. to animate a theme switch. `t` is clamped to
+    /// 0.0..=1.0.
+    ///
+    /// Float fields, pairs, and colors are interpolated linearly. Discrete fields (directions,
+    /// booleans, and hovered flags) switch from `a` to `b` at `t >= 0.5`.
+    pub fn lerp(a: &Style, b: &Style, t: f32) -> Style {
+        let t = t.clamp(0.0, 1.0);
+        if t == 0.0 {
+            return *a;
+        } else if t == 1.0 {
+            return *b;
+        }
+        let mut out = if t >= 0.5 { *b } else { *a };
+        for ((name, from), (_, to)) in a.field_values().into_iter().zip(b.field_values()) {
+            let value = match (from, to) {
+                (StyleFieldValue::F32(from), StyleFieldValue::F32(to)) => {
+                    StyleFieldValue::F32(from + (to - from) * t)
+                }
+                (StyleFieldValue::Vec2(from), StyleFieldValue::Vec2(to)) => {
+                    StyleFieldValue::Vec2(std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t))
+                }
+                _ => continue,
+            };
+            out.set_field_value(name, value);
+        }
+        for (i, color) in out.colors.iter_mut().enumerate() {
+            *color = lerp(a.colors[i], b.colors[i], t);
+        }
+        out
+    }
+}
+
+/// # Layout metrics
+///
+/// Helpers computing sizes and positions the way Dear ImGui's own widgets do, so custom widgets
+/// can match the built-in ones.
+impl Style {
+    /// Returns the height of a framed widget (button, input, combo, etc.) containing a single
+    /// line of text of the given font size.
+    ///
+    /// This is also the size of the square of checkboxes and radio buttons.
+    #[doc(alias = "GetFrameHeight")]
+    pub fn frame_height(&self, font_size: f32) -> f32 {
+        font_size + self.frame_padding[1] * 2.0
+    }
+
+    /// Returns the height of a window title bar for the given font size.
+    pub fn title_bar_height(&self, font_size: f32) -> f32 {
+        self.frame_height(font_size)
+    }
+
+    /// Returns the width of the arrow button of a combo box for the given font size.
+    ///
+    /// Dear ImGui makes the button square, as wide as
+    /// [`frame_height`](Self::frame_height), so custom dropdowns can reserve the same space.
+    pub fn combo_arrow_width(&self, font_size: f32) -> f32 {
+        self.frame_height(font_size)
+    }
+
+    /// Returns the `(min, max)` rectangle a checkbox draws its [`StyleColor::CheckMark`] in,
+    /// for a checkbox frame at `frame_min` that is `frame_size` pixels square (usually
+    /// [`frame_height`](Self::frame_height)).
+    ///
+    /// Like Dear ImGui, the mark is inset by a sixth of the frame size, but at least 1 pixel.
+    pub fn check_mark_rect(&self, frame_min: [f32; 2], frame_size: f32) -> ([f32; 2], [f32; 2]) {
+        let pad = (frame_size / 6.0).trunc().max(1.0);
+        let [x, y] = frame_min;
+        (
+            [x + pad, y + pad],
+            [x + frame_size - pad, y + frame_size - pad],
+        )
+    }
+
+    /// Returns the width of a legacy column which requested `requested` pixels, raised to
+    /// at least [`columns_min_spacing`](Self::columns_min_spacing) so it can't collapse.
+    pub fn column_min_width(&self, requested: f32) -> f32 {
+        requested.max(self.columns_min_spacing)
+    }
+
+    /// Returns the `(min, max)` hit rectangle of the resize handle between two docked
+    /// windows, centered on the split line with a thickness of
+    /// [`docking_separator_size`](Self::docking_separator_size).
+    ///
+    /// `split_line` is where the split line starts and `length` how far it extends, to the
+    /// right if `horizontal` is true and downwards otherwise.
+    #[cfg(feature = "docking")]
+    pub fn docking_separator_rect(
+        &self,
+        split_line: [f32; 2],
+        length: f32,
+        horizontal: bool,
+    ) -> ([f32; 2], [f32; 2]) {
+        let [x, y] = split_line;
+        let half = self.docking_separator_size * 0.5;
+        if horizontal {
+            ([x, y - half], [x + length, y + half])
+        } else {
+            ([x - half, y], [x + half, y + length])
+        }
+    }
+
+    /// Returns the thickness and color of a separator line, for drawing custom dividers which
+    /// match the built-in ones.
+    ///
+    /// Separators with text use [`separator_text_border_size`](Self::separator_text_border_size),
+    /// plain [`Ui::separator`](crate::Ui::separator)s are always 1 pixel thick.
+    pub fn separator_line(&self, with_text: bool) -> (f32, [f32; 4]) {
+        let thickness = if with_text {
+            self.separator_text_border_size
+        } else {
+            1.0
+        };
+        (thickness, self[StyleColor::Separator])
+    }
+
+    /// Computes the layout of a [`Ui::separator_with_text`](crate::Ui::separator_with_text)
+    /// the way Dear ImGui does, for drawing custom separators which match the built-in ones.
+    ///
+    /// `text_size` is the size of the label (e.g. from
+    /// [`Ui::calc_text_size`](crate::Ui::calc_text_size)) and `avail_width` the width the
+    /// separator spans. All positions are relative to the top-left corner of the separator.
+    pub fn separator_text_layout(
+        &self,
+        text_size: [f32; 2],
+        avail_width: f32,
+    ) -> SeparatorTextLayout {
+        let padding = self.separator_text_padding;
+        let thickness = self.separator_text_border_size;
+        let height = (text_size[1] + padding[1] * 2.0).max(thickness);
+
+        let text_x = padding[0]
+            + ((avail_width - text_size[0] - padding[0] * 2.0) * self.separator_text_align[0])
+                .max(0.0);
+        let text_y = ((height - text_size[1]) * self.separator_text_align[1] + 0.99999).trunc();
+        let line_y = (height * 0.5 + 0.99999).trunc();
+
+        let (left_end, right_start) = if text_size[0] > 0.0 {
+            (
+                text_x - self.item_spacing[0],
+                text_x + text_size[0] + self.item_spacing[0],
+            )
+        } else {
+            (avail_width, avail_width)
+        };
+        let line = |x1: f32, x2: f32| {
+            [
+                [x1, line_y - thickness * 0.5],
+                [x2.max(x1), line_y + thickness * 0.5],
+            ]
+        };
+
+        SeparatorTextLayout {
+            size: [avail_width, height],
+            text_min: [text_x, text_y],
+            text_max: [text_x + text_size[0], text_y + text_size[1]],
+            left_line: line(0.0, left_end),
+            right_line: line(right_start, avail_width),
+        }
+    }
+
+    /// Returns the height of a combo box popup showing `item_count` items, of which at most
+    /// `visible_items` are visible at once (8 for [`ComboBoxHeight::Regular`]).
+    ///
+    /// Like Dear ImGui, this assumes items are one line of text tall, separated by
+    /// `item_spacing` and surrounded by the popup's `window_padding`.
+    ///
+    /// [`ComboBoxHeight::Regular`]: crate::ComboBoxHeight::Regular
+    pub fn combo_popup_height(
+        &self,
+        item_count: usize,
+        visible_items: usize,
+        font_size: f32,
+    ) -> f32 {
+        let items = item_count.min(visible_items);
+        let items_height = if items == 0 {
+            0.0
+        } else {
+            (font_size + self.item_spacing[1]) * items as f32 - self.item_spacing[1]
+        };
+        items_height + self.window_padding[1] * 2.0
+    }
+
+    /// Returns the rounding and corner flags Dear ImGui uses for window backgrounds, for
+    /// drawing custom rectangles that match the current theme.
+    ///
+    /// The flags are [`DrawFlags::ROUND_CORNERS_NONE`] when `window_rounding` is zero.
+    ///
+    /// [`DrawFlags::ROUND_CORNERS_NONE`]: crate::draw_list::DrawFlags::ROUND_CORNERS_NONE
+    pub fn window_draw_flags(&self) -> (f32, DrawFlags) {
+        if self.window_rounding > 0.0 {
+            (self.window_rounding, DrawFlags::ROUND_CORNERS_ALL)
+        } else {
+            (0.0, DrawFlags::ROUND_CORNERS_NONE)
+        }
+    }
+
+    /// Returns the size of a tab with a label of `label_size`, as computed by Dear ImGui's
+    /// tab bars.
+    ///
+    /// When `has_close` is true, room is made for the close button, which is `font_size` wide.
+    /// Dear ImGui additionally clamps the width to the tab bar's maximum tab width.
+    pub fn tab_size(&self, label_size: [f32; 2], has_close: bool, font_size: f32) -> [f32; 2] {
+        let mut width = label_size[0] + self.frame_padding[0] * 2.0;
+        if has_close {
+            width += self.item_inner_spacing[0] + font_size;
+        } else {
+            width += 1.0;
+        }
+        [width, label_size[1] + self.frame_padding[1] * 2.0]
+    }
+
+    /// Returns the position and size of a slider grab along a track of `track_len`, for a value
+    /// at `value_ratio` (0.0 to 1.0) of the slider's range.
+    ///
+    /// The position is relative to the start of the track. Like Dear ImGui's float sliders,
+    /// the grab is `grab_min_size` long, unless the track is too short to fit it, and leaves
+    /// 2 pixels of padding at both ends of the track.
+    pub fn slider_grab(&self, track_len: f32, value_ratio: f32) -> (f32, f32) {
+        const GRAB_PADDING: f32 = 2.0;
+        let slider_size = (track_len - GRAB_PADDING * 2.0).max(0.0);
+        let grab_size = self.grab_min_size.min(slider_size);
+        let usable = slider_size - grab_size;
+        (
+            GRAB_PADDING + value_ratio.clamp(0.0, 1.0) * usable,
+            grab_size,
+        )
+    }
+
+    /// Returns the width of the band around a window edge in which the mouse grabs the edge
+    /// to resize the window. The band is centered on the edge.
+    ///
+    /// Like Dear ImGui, the band extends by `window_border_hover_padding` on both sides of
+    /// the edge, or by `touch_extra_padding` if that is larger.
+    pub fn resize_edge_hit_width(&self) -> f32 {
+        let [x, y] = self.touch_extra_padding;
+        x.max(y).max(self.window_border_hover_padding) * 2.0
+    }
+
+    /// Returns the total width of a menu item with a label of `label_w` and a shortcut of
+    /// `shortcut_w`, and the x offset of the shortcut text from the start of the item.
+    ///
+    /// Like Dear ImGui's menus, columns are separated by `item_spacing`, and the spacing is
+    /// omitted when there is no shortcut (`shortcut_w` is 0). The check mark column of
+    /// toggleable items isn't included.
+    pub fn menu_columns(&self, label_w: f32, shortcut_w: f32) -> (f32, f32) {
+        let shortcut_x = label_w + self.item_spacing[0];
+        if shortcut_w > 0.0 {
+            (shortcut_x + shortcut_w, shortcut_x)
+        } else {
+            (label_w, shortcut_x)
+        }
+    }
+
+    /// Returns the rectangle filled by a progress bar with the frame `bb_min`..`bb_max` at
+    /// `fraction` (clamped to 0.0..=1.0).
+    ///
+    /// Like Dear ImGui's progress bars, the fill is inset from the frame by
+    /// `frame_border_size` on every side.
+    pub fn progress_bar_fill_rect(
+        &self,
+        bb_min: [f32; 2],
+        bb_max: [f32; 2],
+        fraction: f32,
+    ) -> ([f32; 2], [f32; 2]) {
+        let inset = self.frame_border_size;
+        let min = [bb_min[0] + inset, bb_min[1] + inset];
+        let max = [
+            (bb_max[0] - inset).max(min[0]),
+            (bb_max[1] - inset).max(min[1]),
+        ];
+        let fill_x = min[0] + (max[0] - min[0]) * fraction.clamp(0.0, 1.0);
+        (min, [fill_x, max[1]])
+    }
+
+    /// Returns the width of a row made of an icon followed by a label, separated by
+    /// `item_inner_spacing` like the parts of built-in widgets.
+    pub fn icon_label_width(&self, icon_w: f32, label_w: f32) -> f32 {
+        icon_w + self.item_inner_spacing[0] + label_w
+    }
+
+    /// Computes the layout of a framed tree node (e.g. a collapsing header) with a label of
+    /// `label_size`, the way Dear ImGui places the arrow and label.
+    ///
+    /// The arrow is a `font_size` square inset by `frame_padding`, and the label follows it
+    /// after another `frame_padding.x * 2`.
+    pub fn tree_node_layout(&self, font_size: f32, label_size: [f32; 2]) -> TreeNodeLayout {
+        let [pad_x, pad_y] = self.frame_padding;
+        TreeNodeLayout {
+            arrow: [[pad_x, pad_y], [pad_x + font_size, pad_y + font_size]],
+            label_pos: [font_size + pad_x * 3.0, pad_y],
+            frame_height: font_size.max(label_size[1]) + pad_y * 2.0,
+            child_indent: self.indent_spacing,
+        }
+    }
+
+    /// Returns the triangle of the resize grip Dear ImGui draws in the bottom-right `corner`
+    /// of a window, with sides `size` long (Dear ImGui uses `font_size * 1.10`, at least
+    /// `window_rounding + 1 + font_size * 0.2`).
+    ///
+    /// The grip is inset from the window border by `window_border_size`. Dear ImGui rounds
+    /// the corner vertex along `window_rounding`; here it is the midpoint of that arc.
+    pub fn resize_grip_points(&self, corner: [f32; 2], size: f32) -> [[f32; 2]; 3] {
+        let border = self.window_border_size;
+        let rounding = self.window_rounding;
+        let inset = rounding + border - rounding * std::f32::consts::FRAC_1_SQRT_2;
+        [
+            [corner[0] - size, corner[1] - border],
+            [corner[0] - border, corner[1] - size],
+            [corner[0] - inset, corner[1] - inset],
+        ]
+    }
+
+    /// Returns the total horizontal and vertical space a child window takes from its content
+    /// region: `window_padding` and `child_border_size` on both sides, plus `scrollbar_size`
+    /// for each visible scrollbar.
+    ///
+    /// Child windows only use `window_padding` when they have a border or use
+    /// [`ChildFlags::ALWAYS_USE_WINDOW_PADDING`](crate::ChildFlags::ALWAYS_USE_WINDOW_PADDING).
+    pub fn child_content_inset(
+        &self,
+        has_vertical_scrollbar: bool,
+        has_horizontal_scrollbar: bool,
+    ) -> [f32; 2] {
+        let scrollbar = |visible: bool| if visible { self.scrollbar_size } else { 0.0 };
+        [
+            (self.window_padding[0] + self.child_border_size) * 2.0
+                + scrollbar(has_vertical_scrollbar),
+            (self.window_padding[1] + self.child_border_size) * 2.0
+                + scrollbar(has_horizontal_scrollbar),
+        ]
+    }
+
+    /// Returns the offset from the mouse cursor at which Dear ImGui places tooltips: `[16, 10]`
+    /// scaled by `mouse_cursor_scale`.
+    pub fn tooltip_offset(&self) -> [f32; 2] {
+        [
+            16.0 * self.mouse_cursor_scale,
+            10.0 * self.mouse_cursor_scale,
+        ]
+    }
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            alpha: 1.0,
+            disabled_alpha: 0.6,
+            window_padding: [8.0, 8.0],
+            window_rounding: 0.0,
+            window_border_size: 1.0,
+            window_border_hover_padding: 4.0,
+            window_min_size: [32.0, 32.0],
+            window_title_align: [0.0, 0.5],
+            window_menu_button_position: Direction::Left,
+            child_rounding: 0.0,
+            child_border_size: 1.0,
+            popup_rounding: 0.0,
+            popup_border_size: 1.0,
+            frame_padding: [4.0, 3.0],
+            frame_rounding: 0.0,
+            frame_border_size: 0.0,
+            item_spacing: [8.0, 4.0],
+            item_inner_spacing: [4.0, 4.0],
+            cell_padding: [4.0, 2.0],
+            touch_extra_padding: [0.0, 0.0],
+            indent_spacing: 21.0,
+            columns_min_spacing: 6.0,
+            scrollbar_size: 14.0,
+            scrollbar_rounding: 9.0,
+            grab_min_size: 12.0,
+            grab_rounding: 0.0,
+            log_slider_deadzone: 4.0,
+            image_border_size: 0.0,
+            tab_rounding: 5.0,
+            tab_border_size: 0.0,
+            tab_close_button_min_width_selected: -1.0,
+            tab_min_width_for_close_button: 0.0,
+            tab_bar_border_size: 1.0,
+            tab_bar_overline_size: 1.0,
+            table_angled_headers_angle: 35.0 * (std::f32::consts::PI / 180.0),
+            table_angled_headers_text_align: [0.5, 0.0],
+            color_button_position: Direction::Right,
+            button_text_align: [0.5, 0.5],
+            selectable_text_align: [0.0, 0.0],
+            separator_text_border_size: 3.0,
+            separator_text_align: [0.0, 0.5],
+            separator_text_padding: [20.0, 3.0],
+            display_window_padding: [19.0, 19.0],
+            display_safe_area_padding: [3.0, 3.0],
+            #[cfg(feature = "docking")]
+            docking_separator_size: 2.0,
+            mouse_cursor_scale: 1.0,
+            anti_aliased_lines: true,
+            anti_aliased_lines_use_tex: true,
+            anti_aliased_fill: true,
+            curve_tessellation_tol: 1.25,
+            circle_tesselation_max_error: 0.3,
+            hover_stationary_delay: 0.15,
+            hover_delay_short: 0.15,
+            hover_delay_normal: 0.4,
+            hover_flags_for_tooltip_mouse: HoveredFlags::STATIONARY
+                | HoveredFlags::DELAY_SHORT
+                | HoveredFlags::ALLOW_WHEN_DISABLED,
+            hover_flags_for_tooltip_nav: HoveredFlags::NO_SHARED_DELAY
+                | HoveredFlags::DELAY_NORMAL
+                | HoveredFlags::ALLOW_WHEN_DISABLED,
+            colors: StyleColor::dark_colors(),
+        }
+    }
+}
+
+impl Index<StyleColor> for Style {
+    type Output = [f32; 4];
+    #[inline]
+    fn index(&self, index: StyleColor) -> &[f32; 4] {
+        &self.colors[index as usize]
+    }
+}
+
+impl IndexMut<StyleColor> for Style {
+    #[inline]
+    fn index_mut(&mut self, index: StyleColor) -> &mut [f32; 4] {
+        &mut self.colors[index as usize]
+    }
+}
+
+/// Builds a [`Style`] with chainable setters, starting from [`Style::default`] or another
+/// style.
+///
+/// ```
+/// # use imgui::*;
+/// let style = StyleBuilder::new()
+///     .window_rounding(6.0)
+///     .frame_padding([8.0, 4.0])
+///     .color(StyleColor::Button, [0.2, 0.4, 0.8, 1.0])
+///     .build();
+///
+/// let mut expected = Style::default();
+/// expected.window_rounding = 6.0;
+/// expected.frame_padding = [8.0, 4.0];
+/// expected[StyleColor::Button] = [0.2, 0.4, 0.8, 1.0];
+/// assert_eq!(style, expected);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct StyleBuilder {
+    style: Style,
+}
+
+impl StyleBuilder {
+    /// Starts from [`Style::default`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts from `style`, e.g. a preset.
+    pub fn from_style(style: Style) -> Self {
+        Self { style }
+    }
+
+    /// Sets a single color.
+    pub fn color(mut self, color: StyleColor, value: [f32; 4]) -> Self {
+        self.style[color] = value;
+        self
+    }
+
+    /// Replaces every color, e.g. with [`StyleColor::light_colors`].
+    pub fn colors(mut self, colors: [[f32; 4]; StyleColor::COUNT]) -> Self {
+        self.style.colors = colors;
+        self
+    }
+
+    /// Returns the built style.
+    pub fn build(self) -> Style {
+        self.style
+    }
+}
+
+// Generates a `StyleBuilder` setter for every field of `Style` except `colors`.
+macro_rules! style_builder_setters {
+    ($($(#[$attr:meta])* $field:ident: $ty:ty,)*) => {
+        impl StyleBuilder {
+            $(
+                $(#[$attr])*
+                #[doc = concat!("Sets [`Style::", stringify!($field), "`].")]
+                pub fn $field(mut self, value: $ty) -> Self {
+                    self.style.$field = value;
+                    self
+                }
+            )*
+        }
+    };
+}
+
+style_builder_setters! {
+    alpha: f32,
+    disabled_alpha: f32,
+    window_padding: [f32; 2],
+    window_rounding: f32,
+    window_border_size: f32,
+    window_border_hover_padding: f32,
+    window_min_size: [f32; 2],
+    window_title_align: [f32; 2],
+    window_menu_button_position: Direction,
+    child_rounding: f32,
+    child_border_size: f32,
+    popup_rounding: f32,
+    popup_border_size: f32,
+    frame_padding: [f32; 2],
+    frame_rounding: f32,
+    frame_border_size: f32,
+    item_spacing: [f32; 2],
+    item_inner_spacing: [f32; 2],
+    cell_padding: [f32; 2],
+    touch_extra_padding: [f32; 2],
+    indent_spacing: f32,
+    columns_min_spacing: f32,
+    scrollbar_size: f32,
+    scrollbar_rounding: f32,
+    grab_min_size: f32,
+    grab_rounding: f32,
+    log_slider_deadzone: f32,
+    image_border_size: f32,
+    tab_rounding: f32,
+    tab_border_size: f32,
+    tab_close_button_min_width_selected: f32,
+    tab_min_width_for_close_button: f32,
+    tab_bar_border_size: f32,
+    tab_bar_overline_size: f32,
+    table_angled_headers_angle: f32,
+    table_angled_headers_text_align: [f32; 2],
+    color_button_position: Direction,
+    button_text_align: [f32; 2],
+    selectable_text_align: [f32; 2],
+    separator_text_border_size: f32,
+    separator_text_align: [f32; 2],
+    separator_text_padding: [f32; 2],
+    display_window_padding: [f32; 2],
+    display_safe_area_padding: [f32; 2],
+    #[cfg(feature = "docking")]
+    docking_separator_size: f32,
+    mouse_cursor_scale: f32,
+    anti_aliased_lines: bool,
+    anti_aliased_lines_use_tex: bool,
+    anti_aliased_fill: bool,
+    curve_tessellation_tol: f32,
+    circle_tesselation_max_error: f32,
+    hover_stationary_delay: f32,
+    hover_delay_short: f32,
+    hover_delay_normal: f32,
+    hover_flags_for_tooltip_mouse: HoveredFlags,
+    hover_flags_for_tooltip_nav: HoveredFlags,
+}
+
+/// Style settings that have no counterpart in Dear ImGui's `ImGuiStyle`.
+///
+/// [`Style`] mirrors the C layout of `ImGuiStyle` and can't grow new fields, so imgui-rs
+/// keeps its own additions here. Access them with
+/// [`Context::style_extras_mut`](crate::Context::style_extras_mut) and
+/// [`Ui::style_extras`](crate::Ui::style_extras).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StyleExtras {
+    /// Top and bottom colors used by [`Ui::gradient_button`](crate::Ui::gradient_button).
+    ///
+    /// When `None`, the `Button` and `ButtonActive` style colors are used.
+    pub button_gradient: Option<([f32; 4], [f32; 4])>,
+    /// Colors returned by [`Ui::status_color`](crate::Ui::status_color).
+    ///
+    /// When `None`, they are derived from the active palette with [`StatusColors::from_style`].
+    pub status_colors: Option<StatusColors>,
+    /// Keeps the keyboard navigation colors in step with the accent color.
+    ///
+    /// When true, [`Context::new_frame`](crate::Context::new_frame) applies
+    /// [`Style::derive_nav_from_accent`] to the active style every frame, so changing
+    /// [`StyleColor::HeaderActive`] also changes the navigation highlights.
+    pub link_nav_to_accent: bool,
+    /// Which window corners are rounded by `window_rounding`, as returned by
+    /// [`Ui::window_draw_flags`](crate::Ui::window_draw_flags) for custom window chrome.
+    ///
+    /// Defaults to [`DrawFlags::ROUND_CORNERS_ALL`]. Dear ImGui's own window backgrounds always
+    /// round every corner.
+    pub window_corner_mask: DrawFlags,
+}
+
+impl Default for StyleExtras {
+    fn default() -> Self {
+        Self {
+            button_gradient: None,
+            status_colors: None,
+            link_nav_to_accent: false,
+            window_corner_mask: DrawFlags::ROUND_CORNERS_ALL,
+        }
+    }
+}
+
+/// A semantic status, see [`StatusColors`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatusKind {
+    Success,
+    Warning,
+    Error,
+    Info,
+}
+
+/// Colors for semantic statuses, which have no [`StyleColor`] of their own.
+///
+/// With the `serde` feature enabled, this implements `Serialize` and `Deserialize`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusColors {
+    pub success: [f32; 4],
+    pub warning: [f32; 4],
+    pub error: [f32; 4],
+    pub info: [f32; 4],
+}
+
+impl StatusColors {
+    /// Derives status colors from a palette's accent color, [`StyleColor::CheckMark`].
+    ///
+    /// Success, warning, and error use green, amber, and red hues, and info uses the accent's
+    /// own hue. All of them take the accent's saturation and lightness so they fit the theme.
+    pub fn from_style(style: &Style) -> Self {
+        Self::from_accent(style[StyleColor::CheckMark])
+    }
+
+    pub(crate) fn from_accent(accent: [f32; 4]) -> Self {
+        let [h, s, l] = rgb_to_hsl([accent[0], accent[1], accent[2]]);
+        // keep statuses recognizable for gray accents
+        let s = s.max(0.6);
+        let color = |hue: f32| {
+            let [r, g, b] = hsl_to_rgb([hue, s, l]);
+            [r, g, b, 1.0]
+        };
+        Self {
+            success: color(120.0 / 360.0),
+            warning: color(40.0 / 360.0),
+            error: color(0.0),
+            info: color(h),
+        }
+    }
+
+    /// Returns the color for the given status.
+    pub fn get(&self, kind: StatusKind) -> [f32; 4] {
+        match kind {
+            StatusKind::Success => self.success,
+            StatusKind::Warning => self.warning,
+            StatusKind::Error => self.error,
+            StatusKind::Info => self.info,
+        }
+    }
+}
+
+/// A minimal set of semantic color tokens, as used by design systems to describe a theme.
+///
+/// See [`Style::from_tokens`] and [`Style::to_tokens`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ThemeTokens {
+    /// The accent color of interactive elements: buttons, headers, check marks, grabs, etc.
+    pub primary: [f32; 4],
+    /// The background of windows, popups and other surfaces.
+    pub surface: [f32; 4],
+    /// Content drawn on top of a surface: text, borders and separators.
+    pub on_surface: [f32; 4],
+    /// Color used to highlight errors. Dear ImGui has no dedicated error color, so this is
+    /// applied to the hovered plot colors.
+    pub error: [f32; 4],
+}
+
+/// The layout of a separator with text. See [`Style::separator_text_layout`].
+///
+/// All positions are relative to the top-left corner of the separator. The line rectangles
+/// have a width of zero when there is no room for that line.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SeparatorTextLayout {
+    /// Total size of the separator
+    pub size: [f32; 2],
+    /// Top-left corner of the text
+    pub text_min: [f32; 2],
+    /// Bottom-right corner of the text
+    pub text_max: [f32; 2],
+    /// `[min, max]` rectangle of the line before the text
+    pub left_line: [[f32; 2]; 2],
+    /// `[min, max]` rectangle of the line after the text
+    pub right_line: [[f32; 2]; 2],
+}
+
+/// The layout of a framed tree node, such as a collapsing header. See
+/// [`Style::tree_node_layout`].
+///
+/// All positions are relative to the top-left corner of the node's frame.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TreeNodeLayout {
+    /// `[min, max]` rectangle of the expand/collapse arrow
+    pub arrow: [[f32; 2]; 2],
+    /// Top-left corner of the label
+    pub label_pos: [f32; 2],
+    /// Height of the frame
+    pub frame_height: f32,
+    /// Indentation of the node's children
+    pub child_indent: f32,
+}
+
+/// A kind of surface drawn by Dear ImGui, used to look up its corner rounding with
+/// [`Style::rounding_for`].
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum SurfaceKind {
+    /// Top-level windows.
+    Window,
+    /// Child windows.
+    Child,
+    /// Popups, menus, and combo box popups.
+    Popup,
+    /// Tooltips, which are rounded like windows rather than popups.
+    Tooltip,
+    /// Frames of most widgets.
+    Frame,
+    /// Scrollbar grabs.
+    Scrollbar,
+    /// Slider grabs.
+    Grab,
+    /// Tabs.
+    Tab,
+}
+
+/// One of Dear ImGui's built-in color presets.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub enum ThemeFamily {
+    /// See [`StyleColor::dark_colors`].
+    Dark,
+    /// See [`StyleColor::light_colors`].
+    Light,
+    /// See [`StyleColor::classic_colors`].
+    Classic,
+}
+
+impl ThemeFamily {
+    /// Returns the full palette of this preset.
+    pub fn colors(self) -> [[f32; 4]; StyleColor::COUNT] {
+        match self {
+            ThemeFamily::Dark => StyleColor::dark_colors(),
+            ThemeFamily::Light => StyleColor::light_colors(),
+            ThemeFamily::Classic => StyleColor::classic_colors(),
+        }
+    }
+}
+
+/// A color identifier for styling.
+///
+/// Which color does what can sometimes be be unobvious. A good way to find a particular color is to use
+/// the [`crate::Ui::show_default_style_editor`] window, set a color to a very bright color, and explore the
+/// [`crate::Ui::show_demo_window`] until you spot it.
+///
+/// Take special note of [`StyleColor::dark_colors`], [`StyleColor::light_colors`], and [`StyleColor::classic_colors`],
+/// which can be used to get the color palettes ImGui uses.
+#[repr(u32)]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum StyleColor {
+    /// Default color of text througout application
+    Text = sys::ImGuiCol_Text,
+    /// Text in areas disabled e.g via [`crate::Ui::begin_disabled`]
+    TextDisabled = sys::ImGuiCol_TextDisabled,
+    /// Background of normal windows
+    WindowBg = sys::ImGuiCol_WindowBg,
+    /// Background of child windows
+    ChildBg = sys::ImGuiCol_ChildBg,
+    /// Background of popups, menus, tooltips windows
+    PopupBg = sys::ImGuiCol_PopupBg,
+    /// Border around windows, frames, etc
+    Border = sys::ImGuiCol_Border,
+    /// Used for a drop-shadow/emboss style effect wherever `Border` is used
+    BorderShadow = sys::ImGuiCol_BorderShadow,
+    /// Background of checkbox, radio button, plot, slider, text input
+    FrameBg = sys::ImGuiCol_FrameBg,
+    /// Same as `FrameBg` but when mouse is hovering over the widget
+    FrameBgHovered = sys::ImGuiCol_FrameBgHovered,
+    /// Same as `FrameBg` but when the mouse is active (e.g mouse is down)
+    FrameBgActive = sys::ImGuiCol_FrameBgActive,
+    /// Window title for inactive windows. Also used as the "docked window tab area" when docking is enabled.
+    TitleBg = sys::ImGuiCol_TitleBg,
+    /// Window title for active windows.
+    TitleBgActive = sys::ImGuiCol_TitleBgActive,
+    /// Color of a floating window when it is "rolled up"
+    TitleBgCollapsed = sys::ImGuiCol_TitleBgCollapsed,
+    /// Main menu bar background, see [`crate::Ui::main_menu_bar`]
+    MenuBarBg = sys::ImGuiCol_MenuBarBg,
+    /// Background area of scrollbar
+    ScrollbarBg = sys::ImGuiCol_ScrollbarBg,
+    /// Movable area of scollbar when "idle"
+    ScrollbarGrab = sys::ImGuiCol_ScrollbarGrab,
+    /// Moveable area of scrollbar when mouse is over it
+    ScrollbarGrabHovered = sys::ImGuiCol_ScrollbarGrabHovered,
+    /// Moveable area of scollbar when it is being clicked on
+    ScrollbarGrabActive = sys::ImGuiCol_ScrollbarGrabActive,
+    /// The color of the tick character inside the checkbox
+    CheckMark = sys::ImGuiCol_CheckMark,
+    /// Color of interactive handle inside various slider widgets
+    SliderGrab = sys::ImGuiCol_SliderGrab,
+    /// Interactive handle when being clicked on
+    SliderGrabActive = sys::ImGuiCol_SliderGrabActive,
+    /// Main frame color of default button
+    Button = sys::ImGuiCol_Button,
+    /// Button when mouse hovers over it
+    ButtonHovered = sys::ImGuiCol_ButtonHovered,
+    /// Button when mouse is down
+    ButtonActive = sys::ImGuiCol_ButtonActive,
+    /// Inactive color for header sections, such as [`crate::Ui::collapsing_header`]
+    Header = sys::ImGuiCol_Header,
+    /// As with `Header` but when hovered
+    HeaderHovered = sys::ImGuiCol_HeaderHovered,
+    /// As with `Header` but when mouse is down
+    HeaderActive = sys::ImGuiCol_HeaderActive,
+    /// Dividing line, e.g [`crate::Ui::separator`]
+    Separator = sys::ImGuiCol_Separator,
+    /// Dividing line when mouse hovered
+    SeparatorHovered = sys::ImGuiCol_SeparatorHovered,
+    /// Dividing line when mouse button down
+    SeparatorActive = sys::ImGuiCol_SeparatorActive,
+    /// Resize handle on windows
+    ResizeGrip = sys::ImGuiCol_ResizeGrip,
+    /// Resize handle when mouse hovered over handle
+    ResizeGripHovered = sys::ImGuiCol_ResizeGripHovered,
+    /// Resize handle when mouse button down
+    ResizeGripActive = sys::ImGuiCol_ResizeGripActive,
+    /// Hovered tab (applies regardless if tab is active, or is in the active window)
+    TabHovered = sys::ImGuiCol_TabHovered,
+    /// Inactive tab color. Applies to both tab widgets and docked windows
+    Tab = sys::ImGuiCol_Tab,
+    /// Color of currently selected tab
+    TabSelected = sys::ImGuiCol_TabSelected,
+    /// Tab horizontal overline, when tab-bar is focused & tab is selected
+    TabSelectedOverline = sys::ImGuiCol_TabSelectedOverline,
+    /// Non-selected, when in an unfocused window
+    TabDimmed = sys::ImGuiCol_TabDimmed,
+    /// Selected tab, in an unfocused window
+    TabDimmedSelected = sys::ImGuiCol_TabDimmedSelected,
+    /// Non-selected, when in an unfocused window
+    TabDimmedSelectedOverline = sys::ImGuiCol_TabDimmedSelectedOverline,
+
+    /// Color of widget which appears when moving windows around, allowing splitting/etc of dock areas
+    #[cfg(feature = "docking")]
+    DockingPreview = sys::ImGuiCol_DockingPreview,
+    /// Colour when black area is present in docking setup (e.g while dragging a window away from a split area, leaving it temporarily empty)
+    #[cfg(feature = "docking")]
+    DockingEmptyBg = sys::ImGuiCol_DockingEmptyBg,
+
+    /// Lines in [`crate::Ui::plot_lines`]
+    PlotLines = sys::ImGuiCol_PlotLines,
+    /// `PlotLines` when hovered
+    PlotLinesHovered = sys::ImGuiCol_PlotLinesHovered,
+    /// Used for [`crate::Ui::plot_histogram`]
+    PlotHistogram = sys::ImGuiCol_PlotHistogram,
+    /// `PlotHistogram` when hovered
+    PlotHistogramHovered = sys::ImGuiCol_PlotHistogramHovered,
+
+    /// Background color of header rows in table widget
+    TableHeaderBg = sys::ImGuiCol_TableHeaderBg,
+    /// Main border color for table, used around whole table and around header cells
+    TableBorderStrong = sys::ImGuiCol_TableBorderStrong,
+    /// Used within border to separate cells
+    TableBorderLight = sys::ImGuiCol_TableBorderLight,
+    /// Background of cells in table
+    TableRowBg = sys::ImGuiCol_TableRowBg,
+    /// Used for alternating row colors, if enabled by `TableFlags::ROW_BG`
+    TableRowBgAlt = sys::ImGuiCol_TableRowBgAlt,
+
+    /// Hyperlink color
+    TextLink = sys::ImGuiCol_TextLink,
+    /// The highlight color used for selection in text inputs
+    TextSelectedBg = sys::ImGuiCol_TextSelectedBg,
+
+    /// Used for drag-and-drop system
+    DragDropTarget = sys::ImGuiCol_DragDropTarget,
+    /// Color of keyboard/gamepad navigation cursor/rectangle, when visible
+    NavCursor = sys::ImGuiCol_NavCursor,
+    /// Highlight window when using CTRL+TAB
+    NavWindowingHighlight = sys::ImGuiCol_NavWindowingHighlight,
+    /// Darken/colorize entire screen behind the CTRL+TAB window list, when active
+    NavWindowingDimBg = sys::ImGuiCol_NavWindowingDimBg,
+    /// Darken/colorize entire screen behind a modal window, when one is active
+    ModalWindowDimBg = sys::ImGuiCol_ModalWindowDimBg,
+}
+
+impl StyleColor {
+    /// All possible `StyleColor` variants
+    pub const VARIANTS: [StyleColor; StyleColor::COUNT] = [
+        StyleColor::Text,
+        StyleColor::TextDisabled,
+        StyleColor::WindowBg,
+        StyleColor::ChildBg,
+        StyleColor::PopupBg,
+        StyleColor::Border,
+        StyleColor::BorderShadow,
+        StyleColor::FrameBg,
+        StyleColor::FrameBgHovered,
+        StyleColor::FrameBgActive,
+        StyleColor::TitleBg,
+        StyleColor::TitleBgActive,
+        StyleColor::TitleBgCollapsed,
+        StyleColor::MenuBarBg,
+        StyleColor::ScrollbarBg,
+        StyleColor::ScrollbarGrab,
+        StyleColor::ScrollbarGrabHovered,
+        StyleColor::ScrollbarGrabActive,
+        StyleColor::CheckMark,
+        StyleColor::SliderGrab,
+        StyleColor::SliderGrabActive,
+        StyleColor::Button,
+        StyleColor::ButtonHovered,
+        StyleColor::ButtonActive,
+        StyleColor::Header,
+        StyleColor::HeaderHovered,
+        StyleColor::HeaderActive,
+        StyleColor::Separator,
+        StyleColor::SeparatorHovered,
+        StyleColor::SeparatorActive,
+        StyleColor::ResizeGrip,
+        StyleColor::ResizeGripHovered,
+        StyleColor::ResizeGripActive,
+        StyleColor::TabHovered,
+        StyleColor::Tab,
+        StyleColor::TabSelected,
+        StyleColor::TabSelectedOverline,
+        StyleColor::TabDimmed,
+        StyleColor::TabDimmedSelected,
+        StyleColor::TabDimmedSelectedOverline,
+        #[cfg(feature = "docking")]
+        StyleColor::DockingPreview,
+        #[cfg(feature = "docking")]
+        StyleColor::DockingEmptyBg,
+        StyleColor::PlotLines,
+        StyleColor::PlotLinesHovered,
+        StyleColor::PlotHistogram,
+        StyleColor::PlotHistogramHovered,
+        StyleColor::TableHeaderBg,
+        StyleColor::TableBorderStrong,
+        StyleColor::TableBorderLight,
+        StyleColor::TableRowBg,
+        StyleColor::TableRowBgAlt,
+        StyleColor::TextLink,
+        StyleColor::TextSelectedBg,
+        StyleColor::DragDropTarget,
+        StyleColor::NavCursor,
+        StyleColor::NavWindowingHighlight,
+        StyleColor::NavWindowingDimBg,
+        StyleColor::ModalWindowDimBg,
+    ];
+    /// Total count of `StyleColor` variants
+    pub const COUNT: usize = sys::ImGuiCol_COUNT as usize;
+
+    /// Every variant with its [`name`](Self::name), sorted by name for binary searching.
+    pub const NAME_TABLE: &'static [(&'static str, StyleColor)] = &[
+        ("Border", StyleColor::Border),
+        ("BorderShadow", StyleColor::BorderShadow),
+        ("Button", StyleColor::Button),
+        ("ButtonActive", StyleColor::ButtonActive),
+        ("ButtonHovered", StyleColor::ButtonHovered),
+        ("CheckMark", StyleColor::CheckMark),
+        ("ChildBg", StyleColor::ChildBg),
+        #[cfg(feature = "docking")]
+        ("DockingEmptyBg", StyleColor::DockingEmptyBg),
+        #[cfg(feature = "docking")]
+        ("DockingPreview", StyleColor::DockingPreview),
+        ("DragDropTarget", StyleColor::DragDropTarget),
+        ("FrameBg", StyleColor::FrameBg),
+        ("FrameBgActive", StyleColor::FrameBgActive),
+        ("FrameBgHovered", StyleColor::FrameBgHovered),
+        ("Header", StyleColor::Header),
+        ("HeaderActive", StyleColor::HeaderActive),
+        ("HeaderHovered", StyleColor::HeaderHovered),
+        ("MenuBarBg", StyleColor::MenuBarBg),
+        ("ModalWindowDimBg", StyleColor::ModalWindowDimBg),
+        ("NavCursor", StyleColor::NavCursor),
+        ("NavWindowingDimBg", StyleColor::NavWindowingDimBg),
+        ("NavWindowingHighlight", StyleColor::NavWindowingHighlight),
+        ("PlotHistogram", StyleColor::PlotHistogram),
+        ("PlotHistogramHovered", StyleColor::PlotHistogramHovered),
+        ("PlotLines", StyleColor::PlotLines),
+        ("PlotLinesHovered", StyleColor::PlotLinesHovered),
+        ("PopupBg", StyleColor::PopupBg),
+        ("ResizeGrip", StyleColor::ResizeGrip),
+        ("ResizeGripActive", StyleColor::ResizeGripActive),
+        ("ResizeGripHovered", StyleColor::ResizeGripHovered),
+        ("ScrollbarBg", StyleColor::ScrollbarBg),
+        ("ScrollbarGrab", StyleColor::ScrollbarGrab),
+        ("ScrollbarGrabActive", StyleColor::ScrollbarGrabActive),
+        ("ScrollbarGrabHovered", StyleColor::ScrollbarGrabHovered),
+        ("Separator", StyleColor::Separator),
+        ("SeparatorActive", StyleColor::SeparatorActive),
+        ("SeparatorHovered", StyleColor::SeparatorHovered),
+        ("SliderGrab", StyleColor::SliderGrab),
+        ("SliderGrabActive", StyleColor::SliderGrabActive),
+        ("Tab", StyleColor::Tab),
+        ("TabDimmed", StyleColor::TabDimmed),
+        ("TabDimmedSelected", StyleColor::TabDimmedSelected),
+        (
+            "TabDimmedSelectedOverline",
+            StyleColor::TabDimmedSelectedOverline,
+        ),
+        ("TabHovered", StyleColor::TabHovered),
+        ("TabSelected", StyleColor::TabSelected),
+        ("TabSelectedOverline", StyleColor::TabSelectedOverline),
+        ("TableBorderLight", StyleColor::TableBorderLight),
+        ("TableBorderStrong", StyleColor::TableBorderStrong),
+        ("TableHeaderBg", StyleColor::TableHeaderBg),
+        ("TableRowBg", StyleColor::TableRowBg),
+        ("TableRowBgAlt", StyleColor::TableRowBgAlt),
+        ("Text", StyleColor::Text),
+        ("TextDisabled", StyleColor::TextDisabled),
+        ("TextLink", StyleColor::TextLink),
+        ("TextSelectedBg", StyleColor::TextSelectedBg),
+        ("TitleBg", StyleColor::TitleBg),
+        ("TitleBgActive", StyleColor::TitleBgActive),
+        ("TitleBgCollapsed", StyleColor::TitleBgCollapsed),
+        ("WindowBg", StyleColor::WindowBg),
+    ];
+
+    /// Returns the color with the given [`name`](Self::name), e.g. `"FrameBgHovered"`.
+    pub fn from_name(name: &str) -> Option<StyleColor> {
+        Self::NAME_TABLE
+            .binary_search_by(|&(entry, _)| entry.cmp(name))
+            .ok()
+            .map(|i| Self::NAME_TABLE[i].1)
+    }
+
+    /// Returns the name of the Style Color.
+    // Note: we do this in Rust (where we have better promises of enums
+    // being of the right type) than in C++ to avoid the FFI. We confirm in
+    // Unit Tests that we are accurate.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StyleColor::Text => "Text",
+            StyleColor::TextDisabled => "TextDisabled",
+            StyleColor::WindowBg => "WindowBg",
+            StyleColor::ChildBg => "ChildBg",
+            StyleColor::PopupBg => "PopupBg",
+            StyleColor::Border => "Border",
+            StyleColor::BorderShadow => "BorderShadow",
+            StyleColor::FrameBg => "FrameBg",
+            StyleColor::FrameBgHovered => "FrameBgHovered",
+            StyleColor::FrameBgActive => "FrameBgActive",
+            StyleColor::TitleBg => "TitleBg",
+            StyleColor::TitleBgActive => "TitleBgActive",
+            StyleColor::TitleBgCollapsed => "TitleBgCollapsed",
+            StyleColor::MenuBarBg => "MenuBarBg",
+            StyleColor::ScrollbarBg => "ScrollbarBg",
+            StyleColor::ScrollbarGrab => "ScrollbarGrab",
+            StyleColor::ScrollbarGrabHovered => "ScrollbarGrabHovered",
+            StyleColor::ScrollbarGrabActive => "ScrollbarGrabActive",
+            StyleColor::CheckMark => "CheckMark",
+            StyleColor::SliderGrab => "SliderGrab",
+            StyleColor::SliderGrabActive => "SliderGrabActive",
+            StyleColor::Button => "Button",
+            StyleColor::ButtonHovered => "ButtonHovered",
+            StyleColor::ButtonActive => "ButtonActive",
+            StyleColor::Header => "Header",
+            StyleColor::HeaderHovered => "HeaderHovered",
+            StyleColor::HeaderActive => "HeaderActive",
+            StyleColor::Separator => "Separator",
+            StyleColor::SeparatorHovered => "SeparatorHovered",
+            StyleColor::SeparatorActive => "SeparatorActive",
+            StyleColor::ResizeGrip => "ResizeGrip",
+            StyleColor::ResizeGripHovered => "ResizeGripHovered",
+            StyleColor::ResizeGripActive => "ResizeGripActive",
+            StyleColor::Tab => "Tab",
+            StyleColor::TabHovered => "TabHovered",
+            StyleColor::TabSelected => "TabSelected",
+            StyleColor::TabDimmed => "TabDimmed",
+            StyleColor::TabDimmedSelected => "TabDimmedSelected",
+            StyleColor::PlotLines => "PlotLines",
+            StyleColor::PlotLinesHovered => "PlotLinesHovered",
+            StyleColor::PlotHistogram => "PlotHistogram",
+            StyleColor::PlotHistogramHovered => "PlotHistogramHovered",
+            StyleColor::TableHeaderBg => "TableHeaderBg",
+            StyleColor::TableBorderStrong => "TableBorderStrong",
+            StyleColor::TableBorderLight => "TableBorderLight",
+            StyleColor::TableRowBg => "TableRowBg",
+            StyleColor::TableRowBgAlt => "TableRowBgAlt",
+            StyleColor::TextSelectedBg => "TextSelectedBg",
+            StyleColor::DragDropTarget => "DragDropTarget",
+            StyleColor::NavCursor => "NavCursor",
+            StyleColor::NavWindowingHighlight => "NavWindowingHighlight",
+            StyleColor::NavWindowingDimBg => "NavWindowingDimBg",
+            StyleColor::ModalWindowDimBg => "ModalWindowDimBg",
+            #[cfg(feature = "docking")]
+            StyleColor::DockingPreview => "DockingPreview",
+            #[cfg(feature = "docking")]
+            StyleColor::DockingEmptyBg => "DockingEmptyBg",
+            StyleColor::TabSelectedOverline => "TabSelectedOverline",
+            StyleColor::TabDimmedSelectedOverline => "TabDimmedSelectedOverline",
+            StyleColor::TextLink => "TextLink",
+        }
+    }
+
+    /// Returns the group of user interface elements this color applies to.
+    pub fn category(self) -> StyleColorCategory {
+        use StyleColor::*;
+        match self {
+            Text | TextDisabled | TextLink | TextSelectedBg => StyleColorCategory::Text,
+            WindowBg | ChildBg | PopupBg | Border | BorderShadow | TitleBg | TitleBgActive
+            | TitleBgCollapsed | MenuBarBg | ScrollbarBg | ScrollbarGrab | ScrollbarGrabHovered
+            | ScrollbarGrabActive | ResizeGrip | ResizeGripHovered | ResizeGripActive
+            | ModalWindowDimBg => StyleColorCategory::Window,
+            FrameBg | FrameBgHovered | FrameBgActive | CheckMark | SliderGrab
+            | SliderGrabActive | Button | ButtonHovered | ButtonActive | Header | HeaderHovered
+            | HeaderActive | Separator | SeparatorHovered | SeparatorActive | DragDropTarget => {
+                StyleColorCategory::Widget
+            }
+            Tab
+            | TabHovered
+            | TabSelected
+            | TabSelectedOverline
+            | TabDimmed
+            | TabDimmedSelected
+            | TabDimmedSelectedOverline => StyleColorCategory::Tab,
+            #[cfg(feature = "docking")]
+            DockingPreview | DockingEmptyBg => StyleColorCategory::Docking,
+            PlotLines | PlotLinesHovered | PlotHistogram | PlotHistogramHovered => {
+                StyleColorCategory::Plot
+            }
+            TableHeaderBg | TableBorderStrong | TableBorderLight | TableRowBg | TableRowBgAlt => {
+                StyleColorCategory::Table
+            }
+            NavCursor | NavWindowingHighlight | NavWindowingDimBg => StyleColorCategory::Navigation,
+        }
+    }
+
+    /// Returns every color in the order a style editor should list them.
+    pub fn display_order(sort: ColorSort) -> Vec<StyleColor> {
+        match sort {
+            ColorSort::EnumOrder => Self::VARIANTS.to_vec(),
+            ColorSort::Alphabetical => Self::NAME_TABLE.iter().map(|&(_, c)| c).collect(),
+            ColorSort::Grouped => {
+                let mut colors = Self::VARIANTS.to_vec();
+                // stable, so colors keep their enum order within a category
+                colors.sort_by_key(|c| c.category());
+                colors
+            }
+        }
+    }
+
+    /// Returns the `[normal, hovered, active]` variants of this color's family, if it has them.
+    fn interactive_family(self) -> Option<[StyleColor; 3]> {
+        use StyleColor::*;
+        let family = match self {
+            FrameBg | FrameBgHovered | FrameBgActive => [FrameBg, FrameBgHovered, FrameBgActive],
+            ScrollbarGrab | ScrollbarGrabHovered | ScrollbarGrabActive => {
+                [ScrollbarGrab, ScrollbarGrabHovered, ScrollbarGrabActive]
+            }
+            Button | ButtonHovered | ButtonActive => [Button, ButtonHovered, ButtonActive],
+            Header | HeaderHovered | HeaderActive => [Header, HeaderHovered, HeaderActive],
+            Separator | SeparatorHovered | SeparatorActive => {
+                [Separator, SeparatorHovered, SeparatorActive]
+            }
+            ResizeGrip | ResizeGripHovered | ResizeGripActive => {
+                [ResizeGrip, ResizeGripHovered, ResizeGripActive]
+            }
+            _ => return None,
+        };
+        Some(family)
+    }
+
+    /// Returns the colors which are not faded by `disabled_alpha` inside a disabled block.
+    ///
+    /// `disabled_alpha` scales the global alpha while widgets are submitted, so it applies to
+    /// nearly every color. These colors are instead drawn at the end of the frame, outside of
+    /// any disabled block, and always appear at their full alpha.
+    pub fn unaffected_by_disabled_alpha() -> &'static [StyleColor] {
+        &[
+            StyleColor::DragDropTarget,
+            StyleColor::NavCursor,
+            StyleColor::NavWindowingHighlight,
+            StyleColor::NavWindowingDimBg,
+            StyleColor::ModalWindowDimBg,
+        ]
+    }
+
+    /// Inverts the RGB channels of every color of a palette, keeping alpha.
+    ///
+    /// This is a quick approximation for turning a light palette into a dark one or vice
+    /// versa; it doesn't produce a polished theme. Hues are flipped too (blue accents become
+    /// orange), see [`invert_palette_preserving_hue`](Self::invert_palette_preserving_hue).
+    pub fn invert_palette(colors: &[[f32; 4]; StyleColor::COUNT]) -> [[f32; 4]; StyleColor::COUNT] {
+        colors.map(|[r, g, b, a]| [1.0 - r, 1.0 - g, 1.0 - b, a])
+    }
+
+    /// Inverts the lightness of every color of a palette, keeping hue, saturation, and alpha.
+    ///
+    /// Like [`invert_palette`](Self::invert_palette), this is only an approximation.
+    pub fn invert_palette_preserving_hue(
+        colors: &[[f32; 4]; StyleColor::COUNT],
+    ) -> [[f32; 4]; StyleColor::COUNT] {
+        colors.map(|[r, g, b, a]| {
+            let [h, s, l] = rgb_to_hsl([r, g, b]);
+            let [r, g, b] = hsl_to_rgb([h, s, 1.0 - l]);
+            [r, g, b, a]
+        })
+    }
+
+    /// Reduces a palette to at most `max_colors` distinct colors (at least one), e.g. for
+    /// renderers with a limited palette.
+    ///
+    /// The closest colors (in RGBA) are merged repeatedly, each swatch being the average of
+    /// the colors it replaces. Returns the palette with every color replaced by its nearest
+    /// swatch, and the swatches.
+    pub fn quantize_palette(
+        colors: &[[f32; 4]; StyleColor::COUNT],
+        max_colors: usize,
+    ) -> ([[f32; 4]; StyleColor::COUNT], Vec<[f32; 4]>) {
+        fn distance(a: [f32; 4], b: [f32; 4]) -> f32 {
+            a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+        }
+
+        // (swatch, number of palette colors merged into it)
+        let mut clusters: Vec<([f32; 4], f32)> = Vec::new();
+        for &color in colors {
+            match clusters.iter_mut().find(|(swatch, _)| *swatch == color) {
+                Some((_, weight)) => *weight += 1.0,
+                None => clusters.push((color, 1.0)),
+            }
+        }
+        while clusters.len() > max_colors.max(1) {
+            let mut closest = (0, 1, f32::INFINITY);
+            for i in 0..clusters.len() {
+                for j in i + 1..clusters.len() {
+                    let d = distance(clusters[i].0, clusters[j].0);
+                    if d < closest.2 {
+                        closest = (i, j, d);
+                    }
+                }
+            }
+            let (i, j, _) = closest;
+            let (b, wb) = clusters.remove(j);
+            let (a, wa) = clusters[i];
+            clusters[i] = (
+                std::array::from_fn(|k| (a[k] * wa + b[k] * wb) / (wa + wb)),
+                wa + wb,
+            );
+        }
+
+        let swatches: Vec<[f32; 4]> = clusters.into_iter().map(|(swatch, _)| swatch).collect();
+        let remapped = colors.map(|color| {
+            swatches
+                .iter()
+                .copied()
+                .min_by(|&a, &b| distance(color, a).total_cmp(&distance(color, b)))
+                .unwrap()
+        });
+        (remapped, swatches)
+    }
+
+    /// Interpolates between two palettes by `t` (clamped to 0.0..=1.0), channel by channel
+    /// in sRGB space, as [`Style::lerp`] does.
+    pub fn lerp_palettes(
+        a: &[[f32; 4]; StyleColor::COUNT],
+        b: &[[f32; 4]; StyleColor::COUNT],
+        t: f32,
+    ) -> [[f32; 4]; StyleColor::COUNT] {
+        let t = t.clamp(0.0, 1.0);
+        std::array::from_fn(|i| lerp(a[i], b[i], t))
+    }
+
+    /// Interpolates between two palettes by `t` (clamped to 0.0..=1.0) in linear light,
+    /// converting to and from sRGB.
+    ///
+    /// Crossfades look smoother than with [`lerp_palettes`](Self::lerp_palettes), which
+    /// produces muddy midtones. Alpha is interpolated as is.
+    pub fn lerp_palettes_linear(
+        a: &[[f32; 4]; StyleColor::COUNT],
+        b: &[[f32; 4]; StyleColor::COUNT],
+        t: f32,
+    ) -> [[f32; 4]; StyleColor::COUNT] {
+        let t = t.clamp(0.0, 1.0);
+        // avoid drift at the ends from the round trip through linear light
+        if t == 0.0 {
+            return *a;
+        } else if t == 1.0 {
+            return *b;
+        }
+        std::array::from_fn(|i| {
+            std::array::from_fn(|c| {
+                if c == 3 {
+                    return a[i][3] + (b[i][3] - a[i][3]) * t;
+                }
+                let (from, to) = (srgb_to_linear(a[i][c]), srgb_to_linear(b[i][c]));
+                linear_to_srgb(from + (to - from) * t)
+            })
+        })
+    }
+
+    /// Builds a palette from colors saved by a possibly different version of Dear ImGui, which
+    /// may have had a different number of colors.
+    ///
+    /// Colors are copied in order as far as they fit, and any missing ones are taken from
+    /// [`dark_colors`](Self::dark_colors). Returns the palette and the number of defaulted
+    /// colors.
+    pub fn load_palette_lenient(values: &[[f32; 4]]) -> ([[f32; 4]; StyleColor::COUNT], usize) {
+        let mut palette = Self::dark_colors();
+        let copied = values.len().min(StyleColor::COUNT);
+        palette[..copied].copy_from_slice(&values[..copied]);
+        (palette, StyleColor::COUNT - copied)
+    }
+
+    /// Returns the "Dark" style colors for ImGui as an array.
+    ///
+    /// You can set this output to [`Style::colors`] to change the style palette.
+    pub fn dark_colors() -> [[f32; 4]; StyleColor::COUNT] {
+        let mut colors = [Default::default(); StyleColor::COUNT];
+
+        colors[Self::Text as usize] = [1.00, 1.00, 1.00, 1.00];
+        colors[Self::TextDisabled as usize] = [0.50, 0.50, 0.50, 1.00];
+        colors[Self::WindowBg as usize] = [0.06, 0.06, 0.06, 0.94];
+        colors[Self::ChildBg as usize] = [0.00, 0.00, 0.00, 0.00];
+        colors[Self::PopupBg as usize] = [0.08, 0.08, 0.08, 0.94];
+        colors[Self::Border as usize] = [0.43, 0.43, 0.50, 0.50];
+        colors[Self::BorderShadow as usize] = [0.00, 0.00, 0.00, 0.00];
+        colors[Self::FrameBg as usize] = [0.16, 0.29, 0.48, 0.54];
+        colors[Self::FrameBgHovered as usize] = [0.26, 0.59, 0.98, 0.40];
+        colors[Self::FrameBgActive as usize] = [0.26, 0.59, 0.98, 0.67];
+        colors[Self::TitleBg as usize] = [0.04, 0.04, 0.04, 1.00];
+        colors[Self::TitleBgActive as usize] = [0.16, 0.29, 0.48, 1.00];
+        colors[Self::TitleBgCollapsed as usize] = [0.00, 0.00, 0.00, 0.51];
+        colors[Self::MenuBarBg as usize] = [0.14, 0.14, 0.14, 1.00];
+        colors[Self::ScrollbarBg as usize] = [0.02, 0.02, 0.02, 0.53];
+        colors[Self::ScrollbarGrab as usize] = [0.31, 0.31, 0.31, 1.00];
+        colors[Self::ScrollbarGrabHovered as usize] = [0.41, 0.41, 0.41, 1.00];
+        colors[Self::ScrollbarGrabActive as usize] = [0.51, 0.51, 0.51, 1.00];
+        colors[Self::CheckMark as usize] = [0.26, 0.59, 0.98, 1.00];
+        colors[Self::SliderGrab as usize] = [0.24, 0.52, 0.88, 1.00];
+        colors[Self::SliderGrabActive as usize] = [0.26, 0.59, 0.98, 1.00];
+        colors[Self::Button as usize] = [0.26, 0.59, 0.98, 0.40];
+        colors[Self::ButtonHovered as usize] = [0.26, 0.59, 0.98, 1.00];
+        colors[Self::ButtonActive as usize] = [0.06, 0.53, 0.98, 1.00];
+        colors[Self::Header as usize] = [0.26, 0.59, 0.98, 0.31];
+        colors[Self::HeaderHovered as usize] = [0.26, 0.59, 0.98, 0.80];
+        colors[Self::HeaderActive as usize] = [0.26, 0.59, 0.98, 1.00];
+        colors[Self::Separator as usize] = colors[Self::Border as usize];
+        colors[Self::SeparatorHovered as usize] = [0.10, 0.40, 0.75, 0.78];
+        colors[Self::SeparatorActive as usize] = [0.10, 0.40, 0.75, 1.00];
+        colors[Self::ResizeGrip as usize] = [0.26, 0.59, 0.98, 0.20];
+        colors[Self::ResizeGripHovered as usize] = [0.26, 0.59, 0.98, 0.67];
+        colors[Self::ResizeGripActive as usize] = [0.26, 0.59, 0.98, 0.95];
+        colors[Self::TabHovered as usize] = colors[Self::HeaderHovered as usize];
+        colors[Self::Tab as usize] = lerp(
+            colors[Self::Header as usize],
+            colors[Self::TitleBgActive as usize],
+            0.80,
+        );
+        colors[Self::TabSelected as usize] = lerp(
+            colors[Self::HeaderActive as usize],
+            colors[Self::TitleBgActive as usize],
+            0.60,
+        );
+        colors[Self::TabSelectedOverline as usize] = colors[Self::HeaderActive as usize];
+        colors[Self::TabDimmed as usize] = lerp(
+            colors[Self::Tab as usize],
+            colors[Self::TitleBg as usize],
+            0.80,
+        );
+        colors[Self::TabDimmedSelected as usize] = lerp(
+            colors[Self::TabSelected as usize],
+            colors[Self::TitleBg as usize],
+            0.40,
+        );
+        colors[Self::TabDimmedSelectedOverline as usize] = [0.50, 0.50, 0.50, 0.00];
+        colors[Self::PlotLines as usize] = [0.61, 0.61, 0.61, 1.00];
+        colors[Self::PlotLinesHovered as usize] = [1.00, 0.43, 0.35, 1.00];
+        colors[Self::PlotHistogram as usize] = [0.90, 0.70, 0.00, 1.00];
+        colors[Self::PlotHistogramHovered as usize] = [1.00, 0.60, 0.00, 1.00];
+        colors[Self::TableHeaderBg as usize] = [0.19, 0.19, 0.20, 1.00];
+        colors[Self::TableBorderStrong as usize] = [0.31, 0.31, 0.35, 1.00];
+        colors[Self::TableBorderLight as usize] = [0.23, 0.23, 0.25, 1.00];
+        colors[Self::TableRowBg as usize] = [0.00, 0.00, 0.00, 0.00];
+        colors[Self::TableRowBgAlt as usize] = [1.00, 1.00, 1.00, 0.06];
+        colors[Self::TextLink as usize] = colors[Self::HeaderActive as usize];
+        colors[Self::TextSelectedBg as usize] = [0.26, 0.59, 0.98, 0.35];
+        colors[Self::DragDropTarget as usize] = [1.00, 1.00, 0.00, 0.90];
+        colors[Self::NavCursor as usize] = [0.26, 0.59, 0.98, 1.00];
+        colors[Self::NavWindowingHighlight as usize] = [1.00, 1.00, 1.00, 0.70];
+        colors[Self::NavWindowingDimBg as usize] = [0.80, 0.80, 0.80, 0.20];
+        colors[Self::ModalWindowDimBg as usize] = [0.80, 0.80, 0.80, 0.35];
+
+        #[cfg(feature = "docking")]
+        {
+            colors[Self::DockingPreview as usize] = std::array::from_fn(|idx| {
+                let multiplier = if idx == 3 { 0.7 } else { 1.0 };
+
+                colors[Self::HeaderActive as usize][idx] * multiplier
+            });
+
+            colors[Self::DockingEmptyBg as usize] = [0.2, 0.2, 0.2, 1.0];
+        }
+
+        colors
+    }
+
+    /// Returns the "Light" style colors for ImGui as an array.
+    ///
+    /// You can set this output to [`Style::colors`] to change the style palette.
+    pub fn light_colors() -> [[f32; 4]; StyleColor::COUNT] {
+        let mut colors = [Default::default(); StyleColor::COUNT];
+
+        colors[Self::Text as usize] = [0.00, 0.00, 0.00, 1.00];
+        colors[Self::TextDisabled as usize] = [0.60, 0.60, 0.60, 1.00];
+        colors[Self::WindowBg as usize] = [0.94, 0.94, 0.94, 1.00];
+        colors[Self::ChildBg as usize] = [0.00, 0.00, 0.00, 0.00];
+        colors[Self::PopupBg as usize] = [1.00, 1.00, 1.00, 0.98];
+        colors[Self::Border as usize] = [0.00, 0.00, 0.00, 0.30];
+        colors[Self::BorderShadow as usize] = [0.00, 0.00, 0.00, 0.00];
+        colors[Self::FrameBg as usize] = [1.00, 1.00, 1.00, 1.00];
+        colors[Self::FrameBgHovered as usize] = [0.26, 0.59, 0.98, 0.40];
+        colors[Self::FrameBgActive as usize] = [0.26, 0.59, 0.98, 0.67];
+        colors[Self::TitleBg as usize] = [0.96, 0.96, 0.96, 1.00];
+        colors[Self::TitleBgActive as usize] = [0.82, 0.82, 0.82, 1.00];
+        colors[Self::TitleBgCollapsed as usize] = [1.00, 1.00, 1.00, 0.51];
+        colors[Self::MenuBarBg as usize] = [0.86, 0.86, 0.86, 1.00];
+        colors[Self::ScrollbarBg as usize] = [0.98, 0.98, 0.98, 0.53];
+        colors[Self::ScrollbarGrab as usize] = [0.69, 0.69, 0.69, 0.80];
+        colors[Self::ScrollbarGrabHovered as usize] = [0.49, 0.49, 0.49, 0.80];
+        colors[Self::ScrollbarGrabActive as usize] = [0.49, 0.49, 0.49, 1.00];
+        colors[Self::CheckMark as usize] = [0.26, 0.59, 0.98, 1.00];
+        colors[Self::SliderGrab as usize] = [0.26, 0.59, 0.98, 0.78];
+        colors[Self::SliderGrabActive as usize] = [0.46, 0.54, 0.80, 0.60];
+        colors[Self::Button as usize] = [0.26, 0.59, 0.98, 0.40];
+        colors[Self::ButtonHovered as usize] = [0.26, 0.59, 0.98, 1.00];
+        colors[Self::ButtonActive as usize] = [0.06, 0.53, 0.98, 1.00];
+        colors[Self::Header as usize] = [0.26, 0.59, 0.98, 0.31];
+        colors[Self::HeaderHovered as usize] = [0.26, 0.59, 0.98, 0.80];
+        colors[Self::HeaderActive as usize] = [0.26, 0.59, 0.98, 1.00];
+        colors[Self::Separator as usize] = [0.39, 0.39, 0.39, 0.62];
+        colors[Self::SeparatorHovered as usize] = [0.14, 0.44, 0.80, 0.78];
+        colors[Self::SeparatorActive as usize] = [0.14, 0.44, 0.80, 1.00];
+        colors[Self::ResizeGrip as usize] = [0.35, 0.35, 0.35, 0.17];
+        colors[Self::ResizeGripHovered as usize] = [0.26, 0.59, 0.98, 0.67];
+        colors[Self::ResizeGripActive as usize] = [0.26, 0.59, 0.98, 0.95];
+        colors[Self::TabHovered as usize] = colors[Self::HeaderHovered as usize];
+        colors[Self::Tab as usize] = lerp(
+            colors[Self::Header as usize],
+            colors[Self::TitleBgActive as usize],
+            0.90,
+        );
+        colors[Self::TabSelected as usize] = lerp(
+            colors[Self::HeaderActive as usize],
+            colors[Self::TitleBgActive as usize],
+            0.60,
+        );
+        colors[Self::TabSelectedOverline as usize] = colors[Self::HeaderActive as usize];
+        colors[Self::TabDimmed as usize] = lerp(
+            colors[Self::Tab as usize],
+            colors[Self::TitleBg as usize],
+            0.80,
+        );
+        colors[Self::TabDimmedSelected as usize] = lerp(
+            colors[Self::TabSelected as usize],
+            colors[Self::TitleBg as usize],
+            0.40,
+        );
+        colors[Self::TabDimmedSelectedOverline as usize] = [0.26, 0.59, 1.00, 0.00];
+        colors[Self::PlotLines as usize] = [0.39, 0.39, 0.39, 1.00];
+        colors[Self::PlotLinesHovered as usize] = [1.00, 0.43, 0.35, 1.00];
+        colors[Self::PlotHistogram as usize] = [0.90, 0.70, 0.00, 1.00];
+        colors[Self::PlotHistogramHovered as usize] = [1.00, 0.45, 0.00, 1.00];
+        colors[Self::TableHeaderBg as usize] = [0.78, 0.87, 0.98, 1.00];
+        colors[Self::TableBorderStrong as usize] = [0.57, 0.57, 0.64, 1.00];
+        colors[Self::TableBorderLight as usize] = [0.68, 0.68, 0.74, 1.00];
+        colors[Self::TableRowBg as usize] = [0.00, 0.00, 0.00, 0.00];
+        colors[Self::TableRowBgAlt as usize] = [0.30, 0.30, 0.30, 0.09];
+        colors[Self::TextLink as usize] = colors[Self::HeaderActive as usize];
+        colors[Self::TextSelectedBg as usize] = [0.26, 0.59, 0.98, 0.35];
+        colors[Self::DragDropTarget as usize] = [0.26, 0.59, 0.98, 0.95];
+        colors[Self::NavCursor as usize] = colors[Self::HeaderHovered as usize];
+        colors[Self::NavWindowingHighlight as usize] = [0.70, 0.70, 0.70, 0.70];
+        colors[Self::NavWindowingDimBg as usize] = [0.20, 0.20, 0.20, 0.20];
+        colors[Self::ModalWindowDimBg as usize] = [0.20, 0.20, 0.20, 0.35];
+
+        #[cfg(feature = "docking")]
+        {
+            colors[Self::DockingPreview as usize] = std::array::from_fn(|idx| {
+                let multiplier = if idx == 3 { 0.7 } else { 1.0 };
+
+                colors[Self::Header as usize][idx] * multiplier
+            });
+
+            colors[Self::DockingEmptyBg as usize] = [0.2, 0.2, 0.2, 1.0];
+        }
+
+        colors
+    }
+
+    /// Returns the "Classic" style colors for ImGui as an array. ImGui now uses
+    /// the "Dark" style colors, which can be made from [`StyleColor::dark_colors`].
+    ///
+    /// You can set this output to [`Style::colors`] to change the style palette.
+    pub fn classic_colors() -> [[f32; 4]; StyleColor::COUNT] {
+        let mut colors = [Default::default(); StyleColor::COUNT];
+
+        colors[Self::Text as usize] = [0.90, 0.90, 0.90, 1.00];
+        colors[Self::TextDisabled as usize] = [0.60, 0.60, 0.60, 1.00];
+        colors[Self::WindowBg as usize] = [0.00, 0.00, 0.00, 0.85];
+        colors[Self::ChildBg as usize] = [0.00, 0.00, 0.00, 0.00];
+        colors[Self::PopupBg as usize] = [0.11, 0.11, 0.14, 0.92];
+        colors[Self::Border as usize] = [0.50, 0.50, 0.50, 0.50];
+        colors[Self::BorderShadow as usize] = [0.00, 0.00, 0.00, 0.00];
+        colors[Self::FrameBg as usize] = [0.43, 0.43, 0.43, 0.39];
+        colors[Self::FrameBgHovered as usize] = [0.47, 0.47, 0.69, 0.40];
+        colors[Self::FrameBgActive as usize] = [0.42, 0.41, 0.64, 0.69];
+        colors[Self::TitleBg as usize] = [0.27, 0.27, 0.54, 0.83];
+        colors[Self::TitleBgActive as usize] = [0.32, 0.32, 0.63, 0.87];
+        colors[Self::TitleBgCollapsed as usize] = [0.40, 0.40, 0.80, 0.20];
+        colors[Self::MenuBarBg as usize] = [0.40, 0.40, 0.55, 0.80];
+        colors[Self::ScrollbarBg as usize] = [0.20, 0.25, 0.30, 0.60];
+        colors[Self::ScrollbarGrab as usize] = [0.40, 0.40, 0.80, 0.30];
+        colors[Self::ScrollbarGrabHovered as usize] = [0.40, 0.40, 0.80, 0.40];
+        colors[Self::ScrollbarGrabActive as usize] = [0.41, 0.39, 0.80, 0.60];
+        colors[Self::CheckMark as usize] = [0.90, 0.90, 0.90, 0.50];
+        colors[Self::SliderGrab as usize] = [1.00, 1.00, 1.00, 0.30];
+        colors[Self::SliderGrabActive as usize] = [0.41, 0.39, 0.80, 0.60];
+        colors[Self::Button as usize] = [0.35, 0.40, 0.61, 0.62];
+        colors[Self::ButtonHovered as usize] = [0.40, 0.48, 0.71, 0.79];
+        colors[Self::ButtonActive as usize] = [0.46, 0.54, 0.80, 1.00];
+        colors[Self::Header as usize] = [0.40, 0.40, 0.90, 0.45];
+        colors[Self::HeaderHovered as usize] = [0.45, 0.45, 0.90, 0.80];
+        colors[Self::HeaderActive as usize] = [0.53, 0.53, 0.87, 0.80];
+        colors[Self::Separator as usize] = [0.50, 0.50, 0.50, 0.60];
+        colors[Self::SeparatorHovered as usize] = [0.60, 0.60, 0.70, 1.00];
+        colors[Self::SeparatorActive as usize] = [0.70, 0.70, 0.90, 1.00];
+        colors[Self::ResizeGrip as usize] = [1.00, 1.00, 1.00, 0.10];
+        colors[Self::ResizeGripHovered as usize] = [0.78, 0.82, 1.00, 0.60];
+        colors[Self::ResizeGripActive as usize] = [0.78, 0.82, 1.00, 0.90];
+        colors[Self::TabHovered as usize] = colors[Self::HeaderHovered as usize];
+        colors[Self::Tab as usize] = lerp(
+            colors[Self::Header as usize],
+            colors[Self::TitleBgActive as usize],
+            0.80,
+        );
+        colors[Self::TabSelected as usize] = lerp(
+            colors[Self::HeaderActive as usize],
+            colors[Self::TitleBgActive as usize],
+            0.60,
+        );
+        colors[Self::TabSelectedOverline as usize] = colors[Self::HeaderActive as usize];
+        colors[Self::TabDimmed as usize] = lerp(
+            colors[Self::Tab as usize],
+            colors[Self::TitleBg as usize],
+            0.80,
+        );
+        colors[Self::TabDimmedSelected as usize] = lerp(
+            colors[Self::TabSelected as usize],
+            colors[Self::TitleBg as usize],
+            0.40,
+        );
+        colors[Self::TabDimmedSelectedOverline as usize] = [0.53, 0.53, 0.87, 0.00];
+        colors[Self::PlotLines as usize] = [1.00, 1.00, 1.00, 1.00];
+        colors[Self::PlotLinesHovered as usize] = [0.90, 0.70, 0.00, 1.00];
+        colors[Self::PlotHistogram as usize] = [0.90, 0.70, 0.00, 1.00];
+        colors[Self::PlotHistogramHovered as usize] = [1.00, 0.60, 0.00, 1.00];
+        colors[Self::TableHeaderBg as usize] = [0.27, 0.27, 0.38, 1.00];
+        colors[Self::TableBorderStrong as usize] = [0.31, 0.31, 0.45, 1.00];
+        colors[Self::TableBorderLight as usize] = [0.26, 0.26, 0.28, 1.00];
+        colors[Self::TableRowBg as usize] = [0.00, 0.00, 0.00, 0.00];
+        colors[Self::TableRowBgAlt as usize] = [1.00, 1.00, 1.00, 0.07];
+        colors[Self::TextLink as usize] = colors[Self::HeaderActive as usize];
+        colors[Self::TextSelectedBg as usize] = [0.00, 0.00, 1.00, 0.35];
+        colors[Self::DragDropTarget as usize] = [1.00, 1.00, 0.00, 0.90];
+        colors[Self::NavCursor as usize] = colors[Self::HeaderHovered as usize];
+        colors[Self::NavWindowingHighlight as usize] = [1.00, 1.00, 1.00, 0.70];
+        colors[Self::NavWindowingDimBg as usize] = [0.80, 0.80, 0.80, 0.20];
+        colors[Self::ModalWindowDimBg as usize] = [0.20, 0.20, 0.20, 0.35];
+
+        #[cfg(feature = "docking")]
+        {
+            colors[Self::DockingPreview as usize] = std::array::from_fn(|idx| {
+                let multiplier = if idx == 3 { 0.7 } else { 1.0 };
+
+                colors[Self::Header as usize][idx] * multiplier
+            });
+
+            colors[Self::DockingEmptyBg as usize] = [0.2, 0.2, 0.2, 1.0];
+        }
+
+        colors
+    }
+
+    /// Returns a dark gray palette with orange accents, in the style of Adobe Photoshop.
+    ///
+    /// You can set this output to [`Style::colors`] to change the style palette.
+    pub fn photoshop_colors() -> [[f32; 4]; StyleColor::COUNT] {
+        let mut colors = Self::dark_colors();
+        let accent = [1.000, 0.391, 0.000, 1.00];
+
+        colors[Self::Text as usize] = [1.000, 1.000, 1.000, 1.00];
+        colors[Self::TextDisabled as usize] = [0.500, 0.500, 0.500, 1.00];
+        colors[Self::WindowBg as usize] = [0.180, 0.180, 0.180, 1.00];
+        colors[Self::ChildBg as usize] = [0.280, 0.280, 0.280, 0.00];
+        colors[Self::PopupBg as usize] = [0.313, 0.313, 0.313, 1.00];
+        colors[Self::Border as usize] = [0.266, 0.266, 0.266, 1.00];
+        colors[Self::FrameBg as usize] = [0.160, 0.160, 0.160, 1.00];
+        colors[Self::FrameBgHovered as usize] = [0.200, 0.200, 0.200, 1.00];
+        colors[Self::FrameBgActive as usize] = [0.280, 0.280, 0.280, 1.00];
+        colors[Self::TitleBg as usize] = [0.148, 0.148, 0.148, 1.00];
+        colors[Self::TitleBgActive as usize] = [0.148, 0.148, 0.148, 1.00];
+        colors[Self::TitleBgCollapsed as usize] = [0.148, 0.148, 0.148, 1.00];
+        colors[Self::MenuBarBg as usize] = [0.195, 0.195, 0.195, 1.00];
+        colors[Self::ScrollbarBg as usize] = [0.160, 0.160, 0.160, 1.00];
+        colors[Self::ScrollbarGrab as usize] = [0.277, 0.277, 0.277, 1.00];
+        colors[Self::ScrollbarGrabHovered as usize] = [0.300, 0.300, 0.300, 1.00];
+        colors[Self::ScrollbarGrabActive as usize] = accent;
+        colors[Self::CheckMark as usize] = [1.000, 1.000, 1.000, 1.00];
+        colors[Self::SliderGrab as usize] = [0.391, 0.391, 0.391, 1.00];
+        colors[Self::SliderGrabActive as usize] = accent;
+        colors[Self::Button as usize] = [1.000, 1.000, 1.000, 0.00];
+        colors[Self::ButtonHovered as usize] = [1.000, 1.000, 1.000, 0.156];
+        colors[Self::ButtonActive as usize] = [1.000, 1.000, 1.000, 0.391];
+        colors[Self::Header as usize] = [0.313, 0.313, 0.313, 1.00];
+        colors[Self::HeaderHovered as usize] = [0.469, 0.469, 0.469, 1.00];
+        colors[Self::HeaderActive as usize] = [0.469, 0.469, 0.469, 1.00];
+        colors[Self::Separator as usize] = colors[Self::Border as usize];
+        colors[Self::SeparatorHovered as usize] = [0.391, 0.391, 0.391, 1.00];
+        colors[Self::SeparatorActive as usize] = accent;
+        colors[Self::ResizeGrip as usize] = [1.000, 1.000, 1.000, 0.25];
+        colors[Self::ResizeGripHovered as usize] = [1.000, 1.000, 1.000, 0.67];
+        colors[Self::ResizeGripActive as usize] = accent;
+        colors[Self::Tab as usize] = [0.098, 0.098, 0.098, 1.00];
+        colors[Self::TabHovered as usize] = [0.352, 0.352, 0.352, 1.00];
+        colors[Self::TabSelected as usize] = [0.195, 0.195, 0.195, 1.00];
+        colors[Self::TabSelectedOverline as usize] = accent;
+        colors[Self::TabDimmed as usize] = [0.098, 0.098, 0.098, 1.00];
+        colors[Self::TabDimmedSelected as usize] = [0.195, 0.195, 0.195, 1.00];
+        colors[Self::TabDimmedSelectedOverline as usize] = [0.469, 0.469, 0.469, 1.00];
+        colors[Self::PlotLines as usize] = [0.469, 0.469, 0.469, 1.00];
+        colors[Self::PlotLinesHovered as usize] = accent;
+        colors[Self::PlotHistogram as usize] = [0.586, 0.586, 0.586, 1.00];
+        colors[Self::PlotHistogramHovered as usize] = accent;
+        colors[Self::TableHeaderBg as usize] = [0.195, 0.195, 0.195, 1.00];
+        colors[Self::TableBorderStrong as usize] = [0.266, 0.266, 0.266, 1.00];
+        colors[Self::TableBorderLight as usize] = [0.230, 0.230, 0.230, 1.00];
+        colors[Self::TextLink as usize] = accent;
+        colors[Self::TextSelectedBg as usize] = [1.000, 1.000, 1.000, 0.156];
+        colors[Self::DragDropTarget as usize] = accent;
+        colors[Self::NavCursor as usize] = accent;
+        colors[Self::NavWindowingHighlight as usize] = accent;
+        colors[Self::NavWindowingDimBg as usize] = [0.000, 0.000, 0.000, 0.586];
+        colors[Self::ModalWindowDimBg as usize] = [0.000, 0.000, 0.000, 0.586];
+
+        #[cfg(feature = "docking")]
+        {
+            colors[Self::DockingPreview as usize] = [1.000, 0.391, 0.000, 0.781];
+            colors[Self::DockingEmptyBg as usize] = [0.180, 0.180, 0.180, 1.00];
+        }
+
+        colors
+    }
+
+    /// Returns the "Cherry" palette, a dark blue-gray palette with cherry red accents.
+    ///
+    /// You can set this output to [`Style::colors`] to change the style palette.
+    pub fn cherry_colors() -> [[f32; 4]; StyleColor::COUNT] {
+        let mut colors = Self::dark_colors();
+        let hi = |a| [0.502, 0.075, 0.256, a];
+        let med = |a| [0.455, 0.198, 0.301, a];
+        let low = |a| [0.232, 0.201, 0.271, a];
+        let bg = |a| [0.200, 0.220, 0.270, a];
+        let text = |a| [0.860, 0.930, 0.890, a];
+
+        colors[Self::Text as usize] = text(0.78);
+        colors[Self::TextDisabled as usize] = text(0.28);
+        colors[Self::WindowBg as usize] = [0.13, 0.14, 0.17, 1.00];
+        colors[Self::ChildBg as usize] = bg(0.58);
+        colors[Self::PopupBg as usize] = bg(0.90);
+        colors[Self::Border as usize] = [0.31, 0.31, 1.00, 0.00];
+        colors[Self::FrameBg as usize] = bg(1.00);
+        colors[Self::FrameBgHovered as usize] = med(0.78);
+        colors[Self::FrameBgActive as usize] = med(1.00);
+        colors[Self::TitleBg as usize] = low(1.00);
+        colors[Self::TitleBgActive as usize] = hi(1.00);
+        colors[Self::TitleBgCollapsed as usize] = bg(0.75);
+        colors[Self::MenuBarBg as usize] = bg(0.47);
+        colors[Self::ScrollbarBg as usize] = bg(1.00);
+        colors[Self::ScrollbarGrab as usize] = [0.09, 0.15, 0.16, 1.00];
+        colors[Self::ScrollbarGrabHovered as usize] = med(0.78);
+        colors[Self::ScrollbarGrabActive as usize] = med(1.00);
+        colors[Self::CheckMark as usize] = [0.71, 0.22, 0.27, 1.00];
+        colors[Self::SliderGrab as usize] = [0.47, 0.77, 0.83, 0.14];
+        colors[Self::SliderGrabActive as usize] = [0.71, 0.22, 0.27, 1.00];
+        colors[Self::Button as usize] = [0.47, 0.77, 0.83, 0.14];
+        colors[Self::ButtonHovered as usize] = med(0.86);
+        colors[Self::ButtonActive as usize] = med(1.00);
+        colors[Self::Header as usize] = med(0.76);
+        colors[Self::HeaderHovered as usize] = med(0.86);
+        colors[Self::HeaderActive as usize] = hi(1.00);
+        colors[Self::Separator as usize] = [0.14, 0.16, 0.19, 1.00];
+        colors[Self::SeparatorHovered as usize] = med(0.78);
+        colors[Self::SeparatorActive as usize] = med(1.00);
+        colors[Self::ResizeGrip as usize] = [0.47, 0.77, 0.83, 0.04];
+        colors[Self::ResizeGripHovered as usize] = med(0.78);
+        colors[Self::ResizeGripActive as usize] = med(1.00);
+        colors[Self::PlotLines as usize] = text(0.63);
+        colors[Self::PlotLinesHovered as usize] = med(1.00);
+        colors[Self::PlotHistogram as usize] = text(0.63);
+        colors[Self::PlotHistogramHovered as usize] = med(1.00);
+        colors[Self::TableHeaderBg as usize] = low(1.00);
+        colors[Self::TableBorderStrong as usize] = [0.14, 0.16, 0.19, 1.00];
+        colors[Self::TableBorderLight as usize] = bg(1.00);
+        colors[Self::TextSelectedBg as usize] = med(0.43);
+        colors[Self::NavCursor as usize] = med(1.00);
+        colors[Self::ModalWindowDimBg as usize] = bg(0.73);
+        derive_secondary_colors(&mut colors);
+
+        colors
+    }
+
+    /// Returns the palette of the "Dracula" color scheme: a dark purple-gray palette with
+    /// purple, pink, and cyan accents.
+    ///
+    /// You can set this output to [`Style::colors`] to change the style palette.
+    pub fn dracula_colors() -> [[f32; 4]; StyleColor::COUNT] {
+        let mut colors = Self::dark_colors();
+        let background = [0.157, 0.165, 0.212, 1.00];
+        let darker = [0.129, 0.133, 0.169, 1.00];
+        let current_line = [0.267, 0.278, 0.353, 1.00];
+        let foreground = [0.973, 0.973, 0.949, 1.00];
+        let comment = [0.384, 0.447, 0.643, 1.00];
+        let cyan = [0.545, 0.914, 0.992, 1.00];
+        let green = [0.314, 0.980, 0.482, 1.00];
+        let orange = [1.000, 0.722, 0.424, 1.00];
+        let pink = [1.000, 0.475, 0.776, 1.00];
+        let purple = [0.741, 0.576, 0.976, 1.00];
+        let yellow = [0.945, 0.980, 0.549, 1.00];
+        let with_alpha = |[r, g, b, _]: [f32; 4], a| [r, g, b, a];
+
+        colors[Self::Text as usize] = foreground;
+        colors[Self::TextDisabled as usize] = comment;
+        colors[Self::WindowBg as usize] = background;
+        colors[Self::PopupBg as usize] = with_alpha(darker, 0.98);
+        colors[Self::Border as usize] = current_line;
+        colors[Self::FrameBg as usize] = current_line;
+        colors[Self::FrameBgHovered as usize] = with_alpha(comment, 0.60);
+        colors[Self::FrameBgActive as usize] = comment;
+        colors[Self::TitleBg as usize] = darker;
+        colors[Self::TitleBgActive as usize] = current_line;
+        colors[Self::TitleBgCollapsed as usize] = with_alpha(darker, 0.75);
+        colors[Self::MenuBarBg as usize] = darker;
+        colors[Self::ScrollbarBg as usize] = with_alpha(darker, 0.60);
+        colors[Self::ScrollbarGrab as usize] = current_line;
+        colors[Self::ScrollbarGrabHovered as usize] = comment;
+        colors[Self::ScrollbarGrabActive as usize] = purple;
+        colors[Self::CheckMark as usize] = green;
+        colors[Self::SliderGrab as usize] = purple;
+        colors[Self::SliderGrabActive as usize] = pink;
+        colors[Self::Button as usize] = current_line;
+        colors[Self::ButtonHovered as usize] = comment;
+        colors[Self::ButtonActive as usize] = purple;
+        colors[Self::Header as usize] = current_line;
+        colors[Self::HeaderHovered as usize] = comment;
+        colors[Self::HeaderActive as usize] = purple;
+        colors[Self::Separator as usize] = current_line;
+        colors[Self::SeparatorHovered as usize] = comment;
+        colors[Self::SeparatorActive as usize] = purple;
+        colors[Self::ResizeGrip as usize] = with_alpha(purple, 0.20);
+        colors[Self::ResizeGripHovered as usize] = with_alpha(purple, 0.67);
+        colors[Self::ResizeGripActive as usize] = pink;
+        colors[Self::PlotLines as usize] = cyan;
+        colors[Self::PlotLinesHovered as usize] = pink;
+        colors[Self::PlotHistogram as usize] = orange;
+        colors[Self::PlotHistogramHovered as usize] = yellow;
+        colors[Self::TableHeaderBg as usize] = current_line;
+        colors[Self::TableBorderStrong as usize] = comment;
+        colors[Self::TableBorderLight as usize] = current_line;
+        colors[Self::TextSelectedBg as usize] = with_alpha(purple, 0.35);
+        colors[Self::DragDropTarget as usize] = with_alpha(yellow, 0.90);
+        colors[Self::NavCursor as usize] = purple;
+        derive_secondary_colors(&mut colors);
+        colors[Self::TextLink as usize] = cyan;
+
+        #[cfg(feature = "docking")]
+        {
+            colors[Self::DockingEmptyBg as usize] = darker;
+        }
+
+        colors
+    }
+}
+
+// sets the tab, link, and docking preview colors from the header and title bar colors, the
+// way Dear ImGui's built-in palettes derive them
+fn derive_secondary_colors(colors: &mut [[f32; 4]; StyleColor::COUNT]) {
+    use StyleColor::*;
+    colors[TabHovered as usize] = colors[HeaderHovered as usize];
+    colors[Tab as usize] = lerp(
+        colors[Header as usize],
+        colors[TitleBgActive as usize],
+        0.80,
+    );
+    colors[TabSelected as usize] = lerp(
+        colors[HeaderActive as usize],
+        colors[TitleBgActive as usize],
+        0.60,
+    );
+    colors[TabSelectedOverline as usize] = colors[HeaderActive as usize];
+    colors[TabDimmed as usize] = lerp(colors[Tab as usize], colors[TitleBg as usize], 0.80);
+    colors[TabDimmedSelected as usize] =
+        lerp(colors[TabSelected as usize], colors[TitleBg as usize], 0.40);
+    colors[TextLink as usize] = colors[HeaderActive as usize];
+
+    #[cfg(feature = "docking")]
+    {
+        let [r, g, b, a] = colors[HeaderActive as usize];
+        colors[DockingPreview as usize] = [r, g, b, a * 0.7];
+    }
+}
+
+impl fmt::Display for StyleColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// A group of user interface elements sharing style colors. See [`StyleColor::category`].
+///
+/// Categories are ordered the way [`ColorSort::Grouped`] lists them.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum StyleColorCategory {
+    Text,
+    Window,
+    Widget,
+    Tab,
+    #[cfg(feature = "docking")]
+    Docking,
+    Plot,
+    Table,
+    Navigation,
+}
+
+/// The order in which [`StyleColor::display_order`] lists colors.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ColorSort {
+    /// The order of [`StyleColor::VARIANTS`], as in Dear ImGui's style editor.
+    EnumOrder,
+    /// Sorted by [`StyleColor::name`].
+    Alphabetical,
+    /// Grouped by [`StyleColor::category`], in enum order within each group.
+    Grouped,
+}
+
+/// The colors [`Style::plot_colormap`] interpolates between.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ColormapKind {
+    /// From [`StyleColor::PlotHistogram`] to [`StyleColor::PlotHistogramHovered`], matching
+    /// the theme.
+    Theme,
+    /// A perceptually uniform dark blue to yellow ramp, like matplotlib's viridis, which stays
+    /// readable regardless of the theme.
+    Viridis,
+}
+
+impl TryFrom<usize> for StyleColor {
+    type Error = InvalidStyleColorValue;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        if value >= StyleColor::COUNT {
+            Err(InvalidStyleColorValue)
+        } else {
+            Ok(Self::VARIANTS[value])
+        }
+    }
+}
+
+impl TryFrom<u32> for StyleColor {
+    type Error = InvalidStyleColorValue;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::try_from(value as usize)
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidStyleColorValue;
+impl fmt::Display for InvalidStyleColorValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("Invalid style color value -- must be between 0..Self::COUNT")
+    }
+}
+impl std::error::Error for InvalidStyleColorValue {}
+
+/// A temporary change in user interface style
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum StyleVar {
+    /// Global alpha applies to everything
+    Alpha(f32),
+    /// Padding within a window
+    WindowPadding([f32; 2]),
+    /// Rounding radius of window corners
+    WindowRounding(f32),
+    /// Thickness of border around windows
+    WindowBorderSize(f32),
+    /// Minimum window size
+    WindowMinSize([f32; 2]),
+    /// Alignment for title bar text
+    WindowTitleAlign([f32; 2]),
+    /// Rounding radius of child window corners
+    ChildRounding(f32),
+    /// Thickness of border around child windows
+    ChildBorderSize(f32),
+    /// Rounding radius of popup window corners
+    PopupRounding(f32),
+    /// Thickness of border around popup/tooltip windows
+    PopupBorderSize(f32),
+    /// Padding within a framed rectangle (used by most widgets)
+    FramePadding([f32; 2]),
+    /// Rounding radius of frame corners (used by most widgets)
+    FrameRounding(f32),
+    /// Thickness of border around frames
+    FrameBorderSize(f32),
+    /// Horizontal and vertical spacing between widgets/lines
+    ItemSpacing([f32; 2]),
+    /// Horizontal and vertical spacing between elements of a composed widget (e.g. a slider and
+    /// its label)
+    ItemInnerSpacing([f32; 2]),
+    /// Horizontal indentation when e.g. entering a tree node
+    IndentSpacing(f32),
+    /// Width of the vertical scrollbar, height of the horizontal scrollbar
+    ScrollbarSize(f32),
+    /// Rounding radius of scrollbar grab corners
+    ScrollbarRounding(f32),
+    /// Minimum width/height of a grab box for slider/scrollbar
+    GrabMinSize(f32),
+    /// Rounding radius of grab corners
+    GrabRounding(f32),
+    /// Rounding radius of upper corners of tabs
+    TabRounding(f32),
+    /// Alignment of button text when button is larger than text
+    ButtonTextAlign([f32; 2]),
+    /// Alignment of selectable text when selectable is larger than text
+    SelectableTextAlign([f32; 2]),
+    /// Padding within a table cell
+    CellPadding([f32; 2]),
+    /// Additional alpha multiplier applied to disabled elements
+    DisabledAlpha(f32),
+    /// Thickness of border around tabs
+    TabBorderSize(f32),
+    /// Thickness of the line under the tab bar
+    TabBarBorderSize(f32),
+    /// Thickness of the tab bar overline, used to highlight the selected tab
+    TabBarOverlineSize(f32),
+    /// Angle of angled headers in tables, in radians
+    TableAngledHeadersAngle(f32),
+    /// Alignment of angled header text within the header cell
+    TableAngledHeadersTextAlign([f32; 2]),
+    /// Thickness of the horizontal line drawn by separator text
+    SeparatorTextBorderSize(f32),
+    /// Alignment of separator text within the separator
+    SeparatorTextAlign([f32; 2]),
+    /// Horizontal offset of separator text from each edge, and vertical spacing around it
+    SeparatorTextPadding([f32; 2]),
+    /// Thickness of border around images
+    ImageBorderSize(f32),
+    /// Thickness of resizing borders between docked windows
+    #[cfg(feature = "docking")]
+    DockingSeparatorSize(f32),
+}
+
+// Maps every `StyleVar` variant to the `Style` field it changes. The generated matches are
+// exhaustive, so a new variant fails to compile until it is listed here.
+macro_rules! style_var_fields {
+    ($($(#[$attr:meta])* $variant:ident => $field:ident,)*) => {
+        impl Style {
+            /// Returns `var` with its payload replaced by the value of the corresponding field
+            /// of this style, e.g. the current frame padding for `StyleVar::FramePadding`.
+            pub fn get_var(&self, var: StyleVar) -> StyleVar {
+                match var {
+                    $($(#[$attr])* StyleVar::$variant(_) => StyleVar::$variant(self.$field),)*
+                }
+            }
+
+            /// Writes the payload of `var` to the corresponding field of this style.
+            pub fn set_var(&mut self, var: StyleVar) {
+                match var {
+                    $($(#[$attr])* StyleVar::$variant(v) => self.$field = v,)*
+                }
+            }
+
+            /// Returns every style var, with its payload taken from this style.
+            pub(crate) fn vars(&self) -> Vec<StyleVar> {
+                vec![$($(#[$attr])* StyleVar::$variant(self.$field),)*]
+            }
+        }
+    };
+}
+
+style_var_fields! {
+    Alpha => alpha,
+    WindowPadding => window_padding,
+    WindowRounding => window_rounding,
+    WindowBorderSize => window_border_size,
+    WindowMinSize => window_min_size,
+    WindowTitleAlign => window_title_align,
+    ChildRounding => child_rounding,
+    ChildBorderSize => child_border_size,
+    PopupRounding => popup_rounding,
+    PopupBorderSize => popup_border_size,
+    FramePadding => frame_padding,
+    FrameRounding => frame_rounding,
+    FrameBorderSize => frame_border_size,
+    ItemSpacing => item_spacing,
+    ItemInnerSpacing => item_inner_spacing,
+    IndentSpacing => indent_spacing,
+    ScrollbarSize => scrollbar_size,
+    ScrollbarRounding => scrollbar_rounding,
+    GrabMinSize => grab_min_size,
+    GrabRounding => grab_rounding,
+    TabRounding => tab_rounding,
+    ButtonTextAlign => button_text_align,
+    SelectableTextAlign => selectable_text_align,
+    CellPadding => cell_padding,
+    DisabledAlpha => disabled_alpha,
+    TabBorderSize => tab_border_size,
+    TabBarBorderSize => tab_bar_border_size,
+    TabBarOverlineSize => tab_bar_overline_size,
+    TableAngledHeadersAngle => table_angled_headers_angle,
+    TableAngledHeadersTextAlign => table_angled_headers_text_align,
+    SeparatorTextBorderSize => separator_text_border_size,
+    SeparatorTextAlign => separator_text_align,
+    SeparatorTextPadding => separator_text_padding,
+    ImageBorderSize => image_border_size,
+    #[cfg(feature = "docking")]
+    DockingSeparatorSize => docking_separator_size,
+}
+
+/// Caches a value derived from a [`Style`], recomputing it only when the style's contents
+/// change.
+///
+/// Changes are detected by hashing every field and color, so the cache can be queried every
+/// frame with the live style.
+///
+/// ```
+/// # use imgui::*;
+/// let mut cache = StyleCache::new();
+/// let style = Style::default();
+/// let bg = cache.get_or_compute(&style, |s| s.composite_over_bg(StyleColor::Button));
+/// # let _ = bg;
+/// ```
+#[derive(Clone, Debug)]
+pub struct StyleCache<V> {
+    entry: Option<(u64, V)>,
+}
+
+impl<V> Default for StyleCache<V> {
+    fn default() -> Self {
+        Self { entry: None }
+    }
+}
+
+impl<V> StyleCache<V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached value, first computing it with `f` if the cache is empty or `style`
+    /// differs from the style it was computed from.
+    pub fn get_or_compute(&mut self, style: &Style, f: impl FnOnce(&Style) -> V) -> &V {
+        let hash = style_hash(style);
+        match &mut self.entry {
+            Some((cached, _)) if *cached == hash => {}
+            entry => *entry = Some((hash, f(style))),
+        }
+        &self.entry.as_ref().unwrap().1
+    }
+
+    /// Discards the cached value.
+    pub fn clear(&mut self) {
+        self.entry = None;
+    }
+}
+
+// hashes the contents of a style, comparing floats bitwise
+fn style_hash(style: &Style) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for (_, value) in style.field_values() {
+        match value {
+            StyleFieldValue::F32(v) => v.to_bits().hash(&mut hasher),
+            StyleFieldValue::Vec2(v) => v.map(f32::to_bits).hash(&mut hasher),
+            StyleFieldValue::Bool(v) => v.hash(&mut hasher),
+            StyleFieldValue::Direction(v) => (v as i32).hash(&mut hasher),
+            StyleFieldValue::HoveredFlags(v) => v.bits().hash(&mut hasher),
+            StyleFieldValue::Color(v) => v.map(f32::to_bits).hash(&mut hasher),
+        }
+    }
+    for color in style.colors {
+        color.map(f32::to_bits).hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Crossfades through a queue of palettes, e.g. to cycle themes automatically.
+///
+/// Each queued palette is faded in from the previous one over its duration, after which the
+/// next one starts. Once the queue is exhausted the last palette is held.
+///
+/// ```
+/// # use imgui::*;
+/// let mut sequencer = PaletteSequencer::new(StyleColor::dark_colors());
+/// sequencer.push(StyleColor::light_colors(), 0.5);
+/// sequencer.push(StyleColor::classic_colors(), 0.5);
+///
+/// let mut style = Style::default();
+/// // every frame:
+/// # let delta_time = 1.0 / 60.0;
+/// style.colors = sequencer.update(delta_time);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PaletteSequencer {
+    current: [[f32; 4]; StyleColor::COUNT],
+    queue: std::collections::VecDeque<([[f32; 4]; StyleColor::COUNT], f32)>,
+    elapsed: f32,
+}
+
+impl PaletteSequencer {
+    /// Creates a sequencer which starts at `initial`, with an empty queue.
+    pub fn new(initial: [[f32; 4]; StyleColor::COUNT]) -> Self {
+        Self {
+            current: initial,
+            queue: Default::default(),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Queues `palette` to be faded in over `duration` seconds after all previously queued
+    /// palettes.
+    pub fn push(&mut self, palette: [[f32; 4]; StyleColor::COUNT], duration: f32) {
+        self.queue.push_back((palette, duration));
+    }
+
+    /// Returns the number of palettes which have not been fully faded in yet.
+    pub fn remaining(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Advances the sequence by `dt` seconds and returns the palette to display.
+    pub fn update(&mut self, dt: f32) -> [[f32; 4]; StyleColor::COUNT] {
+        self.elapsed += dt.max(0.0);
+        while let Some(&(target, duration)) = self.queue.front() {
+            if self.elapsed < duration {
+                let t = self.elapsed / duration;
+                return std::array::from_fn(|i| lerp(self.current[i], target[i], t));
+            }
+            self.elapsed -= duration;
+            self.current = target;
+            self.queue.pop_front();
+        }
+        self.elapsed = 0.0;
+        self.current
+    }
+}
+
+/// Switches between two themes, e.g. light and dark, optionally crossfading their palettes.
+///
+/// ```
+/// # use imgui::*;
+/// let mut light = Style::default();
+/// light.use_light_colors();
+/// let mut toggle = ThemeToggle::new(Style::default(), light).with_crossfade(0.25);
+///
+/// // when the key is pressed:
+/// toggle.toggle();
+/// // every frame:
+/// # let delta_time = 1.0 / 60.0;
+/// let style = toggle.update(delta_time);
+/// # let _ = style;
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThemeToggle {
+    themes: [Style; 2],
+    current: usize,
+    crossfade: f32,
+    sequencer: PaletteSequencer,
+}
+
+impl ThemeToggle {
+    /// Creates a toggle which starts at `first`, without crossfading.
+    pub fn new(first: Style, second: Style) -> Self {
+        Self {
+            sequencer: PaletteSequencer::new(first.colors),
+            themes: [first, second],
+            current: 0,
+            crossfade: 0.0,
+        }
+    }
+
+    /// Crossfades the palettes over `duration` seconds when toggling. Other fields switch
+    /// immediately.
+    pub fn with_crossfade(mut self, duration: f32) -> Self {
+        self.crossfade = duration;
+        self
+    }
+
+    /// Switches to the other theme and returns it.
+    pub fn toggle(&mut self) -> &Style {
+        self.current ^= 1;
+        let target = self.themes[self.current].colors;
+        if self.crossfade > 0.0 {
+            // start from whatever is displayed, even mid-fade
+            self.sequencer = PaletteSequencer::new(self.sequencer.update(0.0));
+            self.sequencer.push(target, self.crossfade);
+        } else {
+            self.sequencer = PaletteSequencer::new(target);
+        }
+        &self.themes[self.current]
+    }
+
+    /// Returns the theme currently toggled to.
+    pub fn current(&self) -> &Style {
+        &self.themes[self.current]
+    }
+
+    /// Advances the crossfade by `dt` seconds and returns the style to display.
+    pub fn update(&mut self, dt: f32) -> Style {
+        Style {
+            colors: self.sequencer.update(dt),
+            ..self.themes[self.current]
+        }
+    }
+}
+
+/// Animates a style from one state to another, e.g. to apply a [`StyleDiff`] smoothly.
+///
+/// Floats, pairs, and colors are interpolated linearly. Discrete fields (directions,
+/// booleans, and hovered flags) snap to their new values once the transition completes.
+///
+/// ```
+/// # use imgui::*;
+/// let base = Style::default();
+/// let edited = Style {
+///     frame_rounding: 6.0,
+///     ..base
+/// };
+/// let mut transition = ThemeTransition::start_diff(&base, &edited.diff(&base), 0.3);
+/// // every frame:
+/// # let delta_time = 1.0 / 60.0;
+/// let style = transition.update(delta_time);
+/// # let _ = style;
+/// ```
+#[derive(Clone, Debug)]
+pub struct ThemeTransition {
+    from: Style,
+    to: Style,
+    duration: f32,
+    elapsed: f32,
+}
+
+impl ThemeTransition {
+    /// Starts animating the values changed by `diff` from their values in `base` over
+    /// `duration` seconds. Values not in the diff keep their values from `base` throughout.
+    pub fn start_diff(base: &Style, diff: &StyleDiff, duration: f32) -> Self {
+        let mut to = *base;
+        diff.apply(&mut to);
+        Self {
+            from: *base,
+            to,
+            duration,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Returns true once the transition has reached its target style.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Advances the transition by `dt` seconds and returns the style to display.
+    pub fn update(&mut self, dt: f32) -> Style {
+        self.elapsed += dt.max(0.0);
+        if self.is_finished() {
+            return self.to;
+        }
+        let mut style = Style::lerp(&self.from, &self.to, self.elapsed / self.duration);
+        for (name, value) in self.from.field_values() {
+            if let StyleFieldValue::Bool(_)
+            | StyleFieldValue::Direction(_)
+            | StyleFieldValue::HoveredFlags(_) = value
+            {
+                style.set_field_value(name, value);
+            }
+        }
+        style
+    }
+}
+
+/// A key identifying a single value of a [`Style`]: either a field or an entry of
+/// [`Style::colors`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum StyleKey {
+    /// A field of [`Style`] other than `colors`, by its Rust name (e.g. `"frame_rounding"`).
+    Field(&'static str),
+    /// An entry of [`Style::colors`].
+    Color(StyleColor),
+}
+
+impl fmt::Display for StyleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StyleKey::Field(name) => f.write_str(name),
+            StyleKey::Color(color) => write!(f, "colors.{}", color.name()),
+        }
+    }
+}
+
+/// The value of a single [`Style`] field or color.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StyleFieldValue {
+    F32(f32),
+    Vec2([f32; 2]),
+    Bool(bool),
+    Direction(Direction),
+    HoveredFlags(HoveredFlags),
+    Color([f32; 4]),
+}
+
+impl StyleFieldValue {
+    // compares floats with a tolerance, and everything else exactly
+    fn approx_eq(&self, other: &StyleFieldValue, epsilon: f32) -> bool {
+        fn close(a: &[f32], b: &[f32], epsilon: f32) -> bool {
+            a.iter()
+                .zip(b)
+                .all(|(a, b)| a == b || (a - b).abs() <= epsilon)
+        }
+        match (self, other) {
+            (StyleFieldValue::F32(a), StyleFieldValue::F32(b)) => close(&[*a], &[*b], epsilon),
+            (StyleFieldValue::Vec2(a), StyleFieldValue::Vec2(b)) => close(a, b, epsilon),
+            (StyleFieldValue::Color(a), StyleFieldValue::Color(b)) => close(a, b, epsilon),
+            _ => self == other,
+        }
+    }
+}
+
+impl fmt::Display for StyleFieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StyleFieldValue::F32(v) => write!(f, "{}", v),
+            StyleFieldValue::Vec2([x, y]) => write!(f, "[{}, {}]", x, y),
+            StyleFieldValue::Bool(v) => write!(f, "{}", v),
+            StyleFieldValue::Direction(v) => write!(f, "{:?}", v),
+            StyleFieldValue::HoveredFlags(v) => write!(f, "{:?}", v),
+            StyleFieldValue::Color([r, g, b, a]) => write!(f, "[{}, {}, {}, {}]", r, g, b, a),
+        }
+    }
+}
+
+impl From<f32> for StyleFieldValue {
+    fn from(v: f32) -> Self {
+        StyleFieldValue::F32(v)
+    }
+}
+
+impl From<[f32; 2]> for StyleFieldValue {
+    fn from(v: [f32; 2]) -> Self {
+        StyleFieldValue::Vec2(v)
+    }
+}
+
+impl From<bool> for StyleFieldValue {
+    fn from(v: bool) -> Self {
+        StyleFieldValue::Bool(v)
+    }
+}
+
+impl From<Direction> for StyleFieldValue {
+    fn from(v: Direction) -> Self {
+        StyleFieldValue::Direction(v)
+    }
+}
+
+impl From<HoveredFlags> for StyleFieldValue {
+    fn from(v: HoveredFlags) -> Self {
+        StyleFieldValue::HoveredFlags(v)
+    }
+}
+
+impl From<[f32; 4]> for StyleFieldValue {
+    fn from(v: [f32; 4]) -> Self {
+        StyleFieldValue::Color(v)
+    }
+}
+
+// The inverse of the `From` conversions into `StyleFieldValue`
+trait FromFieldValue: Sized {
+    fn from_field_value(value: StyleFieldValue) -> Option<Self>;
+}
+
+macro_rules! from_field_value {
+    ($($ty:ty => $variant:ident,)*) => {
+        $(impl FromFieldValue for $ty {
+            fn from_field_value(value: StyleFieldValue) -> Option<Self> {
+                match value {
+                    StyleFieldValue::$variant(v) => Some(v),
+                    _ => None,
+                }
+            }
+        })*
+    };
+}
+
+from_field_value! {
+    f32 => F32,
+    [f32; 2] => Vec2,
+    bool => Bool,
+    Direction => Direction,
+    HoveredFlags => HoveredFlags,
+}
+
+/// An error returned by [`Style::apply_kv`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StyleKvError {
+    /// The key names neither a field nor a color.
+    UnknownKey(String),
+    /// The value could not be parsed for the given key.
+    InvalidValue {
+        /// The key being assigned.
+        key: String,
+        /// The value which failed to parse.
+        value: String,
+    },
+}
+
+impl fmt::Display for StyleKvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StyleKvError::UnknownKey(key) => write!(f, "Unknown style key `{}`", key),
+            StyleKvError::InvalidValue { key, value } => {
+                write!(f, "Invalid value `{}` for style key `{}`", value, key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StyleKvError {}
+
+/// An error returned by [`Style::load_ini`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StyleIniError {
+    /// A line is neither blank, a comment, a section header, nor a `key=value` pair.
+    MissingEquals {
+        /// The 1-based line number.
+        line: usize,
+    },
+    /// The value could not be parsed for the given key.
+    InvalidValue {
+        /// The 1-based line number.
+        line: usize,
+        /// The key being assigned.
+        key: String,
+        /// The value which failed to parse.
+        value: String,
+    },
+}
+
+impl fmt::Display for StyleIniError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StyleIniError::MissingEquals { line } => {
+                write!(f, "Line {}: expected `key=value`", line)
+            }
+            StyleIniError::InvalidValue { line, key, value } => write!(
+                f,
+                "Line {}: invalid value `{}` for style key `{}`",
+                line, value, key
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StyleIniError {}
+
+// parses a value of the same kind as `current`
+fn parse_field_value(current: StyleFieldValue, value: &str) -> Option<StyleFieldValue> {
+    let value = value.trim();
+    Some(match current {
+        StyleFieldValue::F32(_) => StyleFieldValue::F32(value.parse().ok()?),
+        StyleFieldValue::Vec2(_) => {
+            let (x, y) = value.split_once(',')?;
+            StyleFieldValue::Vec2([x.trim().parse().ok()?, y.trim().parse().ok()?])
+        }
+        StyleFieldValue::Bool(_) => StyleFieldValue::Bool(value.parse().ok()?),
+        StyleFieldValue::Direction(_) => StyleFieldValue::Direction(match value {
+            "None" => Direction::None,
+            "Left" => Direction::Left,
+            "Right" => Direction::Right,
+            "Up" => Direction::Up,
+            "Down" => Direction::Down,
+            _ => return None,
+        }),
+        StyleFieldValue::HoveredFlags(_) => {
+            StyleFieldValue::HoveredFlags(HoveredFlags::from_bits(value.parse().ok()?)?)
+        }
+        StyleFieldValue::Color(_) => StyleFieldValue::Color(parse_hex_color(value)?),
+    })
+}
+
+// parses a hex color with a leading `#`, as used by `Style::apply_kv`
+fn parse_hex_color(value: &str) -> Option<[f32; 4]> {
+    color_from_hex(value.strip_prefix('#')?).ok()
+}
+
+/// An error returned by [`color_from_hex`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ColorParseError {
+    /// The number of hex digits isn't 3, 4, 6, or 8.
+    InvalidLength(usize),
+    /// The string contains a character which isn't a hex digit.
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorParseError::InvalidLength(len) => write!(
+                f,
+                "Invalid hex color length {} -- expected 3, 4, 6, or 8 digits",
+                len
+            ),
+            ColorParseError::InvalidDigit(c) => write!(f, "Invalid hex digit `{}` in color", c),
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+/// Parses a color written as `RGB`, `RGBA`, `RRGGBB`, or `RRGGBBAA` hex digits, with or without
+/// a leading `#`, into normalized RGBA. Colors without alpha are opaque.
+///
+/// ```
+/// # use imgui::*;
+/// # fn main() -> Result<(), ColorParseError> {
+/// let mut style = Style::default();
+/// style[StyleColor::Button] = color_from_hex("#3377ff")?;
+/// assert_eq!(color_from_hex("#abc")?, color_from_hex("aabbccff")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn color_from_hex(hex: &str) -> Result<[f32; 4], ColorParseError> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    let mut digits = [0u8; 8];
+    let mut len = 0;
+    for c in hex.chars() {
+        let digit = c.to_digit(16).ok_or(ColorParseError::InvalidDigit(c))?;
+        if len < digits.len() {
+            digits[len] = digit as u8;
+        }
+        len += 1;
+    }
+    let channel = |i: usize| -> f32 {
+        let byte = match len {
+            3 | 4 => digits[i] * 17,
+            _ => digits[i * 2] * 16 + digits[i * 2 + 1],
+        };
+        f32::from(byte) / 255.0
+    };
+    match len {
+        3 | 6 => Ok([channel(0), channel(1), channel(2), 1.0]),
+        4 | 8 => Ok([channel(0), channel(1), channel(2), channel(3)]),
+        _ => Err(ColorParseError::InvalidLength(len)),
+    }
+}
+
+/// Formats a color as `#RRGGBB`, or `#RRGGBBAA` if `include_alpha` is true, the inverse of
+/// [`color_from_hex`]. Channels are clamped to 0.0..=1.0 and rounded to 8 bits.
+pub fn color_to_hex(color: [f32; 4], include_alpha: bool) -> String {
+    let byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let [r, g, b, a] = color.map(byte);
+    if include_alpha {
+        format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+    } else {
+        format!("#{:02X}{:02X}{:02X}", r, g, b)
+    }
+}
+
+/// Packs a color into a `0xAABBGGRR` `u32`, like Dear ImGui's `ImU32` and
+/// `ColorConvertFloat4ToU32`. Channels are clamped to 0.0..=1.0 and rounded to 8 bits.
+pub fn color_to_u32(color: [f32; 4]) -> u32 {
+    let [r, g, b, a] = color;
+    ImColor32::from_rgba_f32s(r, g, b, a).to_bits()
+}
+
+/// Unpacks a `0xAABBGGRR` `u32` into a color, the inverse of [`color_to_u32`].
+pub fn color_from_u32(packed: u32) -> [f32; 4] {
+    ImColor32::from_bits(packed).to_rgba_f32s()
+}
+
+/// Returns the WCAG 2.1 contrast ratio between two colors, from 1.0 (no contrast) to 21.0
+/// (black on white).
+///
+/// `fg` is composited over `bg` using its alpha first; `bg` is treated as opaque.
+pub fn contrast_ratio(fg: [f32; 4], bg: [f32; 4]) -> f32 {
+    fn luminance([r, g, b, _]: [f32; 4]) -> f32 {
+        0.2126 * srgb_to_linear(r) + 0.7152 * srgb_to_linear(g) + 0.0722 * srgb_to_linear(b)
+    }
+    let [r, g, b, _] = bg;
+    let bg = [r, g, b, 1.0];
+    let fg = luminance(blend_over(fg, bg));
+    let bg = luminance(bg);
+    (fg.max(bg) + 0.05) / (fg.min(bg) + 0.05)
+}
+
+// Generates reflection over every field of `Style` except `colors`, which are
+// handled per `StyleColor`. This must list fields in declaration order, each with the first
+// sentence of its doc comment.
+macro_rules! style_fields {
+    ($($(#[$attr:meta])* $field:ident => $doc:literal,)*) => {
+        impl Style {
+            /// The name and a one-line description of every field except `colors`, in
+            /// declaration order.
+            const FIELD_DOCS: &'static [(&'static str, &'static str)] =
+                &[$($(#[$attr])* (stringify!($field), $doc),)*];
+
+            /// Returns the name and value of every field except `colors`, in declaration order.
+            fn field_values(&self) -> Vec<(&'static str, StyleFieldValue)> {
+                vec![$($(#[$attr])* (stringify!($field), StyleFieldValue::from(self.$field)),)*]
+            }
+
+            /// Sets the field called `name`, returning false if there is no such field or
+            /// `value` is of the wrong type.
+            fn set_field_value(&mut self, name: &str, value: StyleFieldValue) -> bool {
+                match name {
+                    $($(#[$attr])* stringify!($field) => match FromFieldValue::from_field_value(value) {
+                        Some(v) => {
+                            self.$field = v;
+                            true
+                        }
+                        None => false,
+                    },)*
+                    _ => false,
+                }
+            }
+        }
+    };
+}
+
+style_fields! {
+    alpha => "Global alpha applies to everything",
+    disabled_alpha => "Additional alpha multiplier applied to disabled elements",
+    window_padding => "Padding within a window",
+    window_rounding => "Rounding radius of window corners",
+    window_border_size => "Thickness of border around windows",
+    window_border_hover_padding => "Hit-testing extent outside and inside the window border used for resizing",
+    window_min_size => "Minimum window size",
+    window_title_align => "Alignment for title bar text",
+    window_menu_button_position => "Side of the collapsing/docking button in the title bar (left/right)",
+    child_rounding => "Rounding radius of child window corners",
+    child_border_size => "Thickness of border around child windows",
+    popup_rounding => "Rounding radius of popup window corners",
+    popup_border_size => "Thickness of border around popup/tooltip windows",
+    frame_padding => "Padding within a framed rectangle (used by most widgets)",
+    frame_rounding => "Rounding radius of frame corners (used by most widgets)",
+    frame_border_size => "Thickness of border around frames",
+    item_spacing => "Horizontal and vertical spacing between widgets/lines",
+    item_inner_spacing => "Horizontal and vertical spacing between elements of a composed widget (e.g. a slider and its label)",
+    cell_padding => "Padding within a table cell",
+    touch_extra_padding => "Expand reactive bounding box for touch-based system where touch position is not accurate enough",
+    indent_spacing => "Horizontal indentation when e.g. entering a tree node",
+    columns_min_spacing => "Minimum horizontal spacing between two columns",
+    scrollbar_size => "Width of the vertical scrollbar, height of the horizontal scrollbar",
+    scrollbar_rounding => "Rounding radius of scrollbar grab corners",
+    grab_min_size => "Minimum width/height of a grab box for slider/scrollbar",
+    grab_rounding => "Rounding radius of grab corners",
+    log_slider_deadzone => "The size in pixels of the dead-zone around zero on logarithmic sliders that cross zero",
+    image_border_size => "Thickness of border around images drawn with Image",
+    tab_rounding => "Rounding radius of upper corners of tabs",
+    tab_border_size => "Thickness of border around tabs",
+    tab_close_button_min_width_selected => "Minimum width for close button to appear on the selected tab when hovered",
+    tab_min_width_for_close_button => "Minimum width for close button to appear on an unselected tab when hovered",
+    tab_bar_border_size => "Thickness of tab-bar separator, which takes on the tab active color to denote focus",
+    tab_bar_overline_size => "Thickness of tab-bar overline, which highlights the selected tab-bar",
+    table_angled_headers_angle => "Angle of angled headers (supported values range from -50 degrees to +50 degrees)",
+    table_angled_headers_text_align => "Alignment of angled headers within the cell",
+    color_button_position => "Side of the color button in color editor widgets (left/right)",
+    button_text_align => "Alignment of button text when button is larger than text",
+    selectable_text_align => "Alignment of selectable text when selectable is larger than text",
+    separator_text_border_size => "Thickness of border in Ui::separator_with_text",
+    separator_text_align => "Alignment of text within the separator",
+    separator_text_padding => "Horizontal offset of text from each edge of the separator + spacing on other axis",
+    display_window_padding => "Window positions are clamped to be visible within the display area or monitors by at least this amount",
+    display_safe_area_padding => "If you cannot see the edges of your screen (e.g. on a TV), increase the safe area padding",
+    #[cfg(feature = "docking")]
+    docking_separator_size => "Thickness of resizing border between docked windows",
+    mouse_cursor_scale => "Scale software-rendered mouse cursor",
+    anti_aliased_lines => "Enable anti-aliased lines/borders",
+    anti_aliased_lines_use_tex => "Enable anti-aliased lines/borders using textures where possible",
+    anti_aliased_fill => "Enable anti-aliased edges around filled shapes (rounded rectangles, circles, etc.)",
+    curve_tessellation_tol => "Tessellation tolerance when using path_bezier_curve_to without a specific number of segments",
+    circle_tesselation_max_error => "Maximum error (in pixels) allowed when drawing circles or rounded corner rectangles with no explicit segment count specified",
+    hover_stationary_delay => "Delay on hover before Ui::is_item_hovered_with_flags + HoveredFlags::STATIONARY returns true",
+    hover_delay_short => "Delay on hover before Ui::is_item_hovered_with_flags + HoveredFlags::DELAY_SHORT returns true",
+    hover_delay_normal => "Delay on hover before Ui::is_item_hovered_with_flags + HoveredFlags::DELAY_NORMAL returns true",
+    hover_flags_for_tooltip_mouse => "Default flags when using HoveredFlags::FOR_TOOLTIP or Ui::begin_tooltip or Ui::tooltip_text while using mouse",
+    hover_flags_for_tooltip_nav => "Default flags when using HoveredFlags::FOR_TOOLTIP or Ui::begin_tooltip or Ui::tooltip_text while using keyboard/gamepad",
+}
+
+/// A single value which differs between two styles. See [`Style::diff`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct StyleDiffEntry {
+    /// Which field or color differs.
+    pub key: StyleKey,
+    /// The value in the base style.
+    pub base: StyleFieldValue,
+    /// The value in the compared style.
+    pub value: StyleFieldValue,
+}
+
+/// The set of values which differ between a style and a base style. See [`Style::diff`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StyleDiff {
+    entries: Vec<StyleDiffEntry>,
+}
+
+impl StyleDiff {
+    /// Returns every differing value, fields first (in declaration order) and then colors.
+    pub fn entries(&self) -> &[StyleDiffEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of differing values.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the two styles were identical.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Sets every differing value on `target`, e.g. to replay changes made to one style onto
+    /// another.
+    pub fn apply(&self, target: &mut Style) {
+        for entry in &self.entries {
+            match (entry.key, entry.value) {
+                (StyleKey::Color(color), StyleFieldValue::Color(value)) => target[color] = value,
+                (StyleKey::Field(name), value) => {
+                    target.set_field_value(name, value);
+                }
+                // diffs only pair colors with color values
+                (StyleKey::Color(_), _) => {}
+            }
+        }
+    }
+
+    /// Writes the diff as a table, with one `key: base -> value` line per entry.
+    pub fn fmt_table(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(w, "{}: {} -> {}", entry.key, entry.base, entry.value)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the new values as a JSON object keyed like [`StyleKey`]'s `Display`, e.g.
+    /// `{"frame_rounding":4,"colors.Button":[0.2,0.4,1,1]}`.
+    ///
+    /// Two-component fields and colors are arrays, directions are strings, and hovered flags
+    /// are their integer bits. Non-finite numbers are written as `null`.
+    pub fn write_json(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        fn number(w: &mut impl fmt::Write, v: f32) -> fmt::Result {
+            if v.is_finite() {
+                write!(w, "{}", v)
+            } else {
+                w.write_str("null")
+            }
+        }
+        fn array(w: &mut impl fmt::Write, values: &[f32]) -> fmt::Result {
+            w.write_char('[')?;
+            for (i, &v) in values.iter().enumerate() {
+                if i > 0 {
+                    w.write_char(',')?;
+                }
+                number(w, v)?;
+            }
+            w.write_char(']')
+        }
+
+        w.write_char('{')?;
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                w.write_char(',')?;
+            }
+            // keys are Rust identifiers and color names, which never need escaping
+            write!(w, "\"{}\":", entry.key)?;
+            match entry.value {
+                StyleFieldValue::F32(v) => number(w, v)?,
+                StyleFieldValue::Vec2(v) => array(w, &v)?,
+                StyleFieldValue::Bool(v) => write!(w, "{}", v)?,
+                StyleFieldValue::Direction(v) => write!(w, "\"{:?}\"", v)?,
+                StyleFieldValue::HoveredFlags(v) => write!(w, "{}", v.bits())?,
+                StyleFieldValue::Color(v) => array(w, &v)?,
+            }
+        }
+        w.write_char('}')
+    }
+}
+
+// lerps a color with the given value
+fn lerp(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+}
+
+// composites `src` over `dst` with source-over blending, using straight
+// (non-premultiplied) alpha
+fn blend_over(src: [f32; 4], dst: [f32; 4]) -> [f32; 4] {
+    let alpha = src[3] + dst[3] * (1.0 - src[3]);
+    if alpha <= 0.0 {
+        return [0.0; 4];
+    }
+    let mut out: [f32; 4] =
+        std::array::from_fn(|i| (src[i] * src[3] + dst[i] * dst[3] * (1.0 - src[3])) / alpha);
+    out[3] = alpha;
+    out
+}
+
+// converts an RGB color to hue, saturation, and lightness, all in 0..=1
+fn rgb_to_hsl([r, g, b]: [f32; 3]) -> [f32; 3] {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) * 0.5;
+    let d = max - min;
+    if d <= 0.0 {
+        return [0.0, 0.0, l];
+    }
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    [h / 6.0, s, l]
+}
+
+// the inverse of `rgb_to_hsl`
+fn hsl_to_rgb([h, s, l]: [f32; 3]) -> [f32; 3] {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h = h * 6.0;
+    let x = c * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = l - c * 0.5;
+    [r + m, g + m, b + m]
+}
+
+// the `NavCursor` and `NavWindowingHighlight` colors for an accent color, matching how the
+// dark preset pairs its blue accent with an opaque nav cursor
+pub(crate) fn nav_colors_from_accent([r, g, b, _]: [f32; 4]) -> [[f32; 4]; 2] {
+    [[r, g, b, 1.0], [r, g, b, 0.70]]
+}
+
+/// Converts an sRGB-encoded color channel to linear light, using the standard sRGB transfer
+/// function.
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear color channel to sRGB encoding. This is the inverse of
+/// [`srgb_to_linear`].
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_scaling() {
+        let (_guard, ctx) = crate::test::test_ctx();
+        let mut style = *ctx.style();
+        style.window_padding = [1.0, 2.0];
+        style.window_rounding = 3.0;
+        style.window_min_size = [4.0, 5.0];
+        style.child_rounding = 6.0;
+        style.popup_rounding = 7.0;
+        style.frame_padding = [8.0, 9.0];
+        style.frame_rounding = 10.0;
+        style.item_spacing = [11.0, 12.0];
+        style.item_inner_spacing = [13.0, 14.0];
+        style.touch_extra_padding = [15.0, 16.0];
+        style.indent_spacing = 17.0;
+        style.columns_min_spacing = 18.0;
+        style.scrollbar_size = 19.0;
+        style.scrollbar_rounding = 20.0;
+        style.grab_min_size = 21.0;
+        style.grab_rounding = 22.0;
+        style.log_slider_deadzone = 29.0;
+        style.tab_rounding = 23.0;
+        style.display_window_padding = [24.0, 25.0];
+        style.display_safe_area_padding = [26.0, 27.0];
+        style.mouse_cursor_scale = 28.0;
+        style.cell_padding = [29.0, 30.0];
+        style.scale_all_sizes(2.0);
+        assert_eq!(style.window_padding, [2.0, 4.0]);
+        assert_eq!(style.window_rounding, 6.0);
+        assert_eq!(style.window_min_size, [8.0, 10.0]);
+        assert_eq!(style.child_rounding, 12.0);
+        assert_eq!(style.popup_rounding, 14.0);
+        assert_eq!(style.frame_padding, [16.0, 18.0]);
+        assert_eq!(style.frame_rounding, 20.0);
+        assert_eq!(style.item_spacing, [22.0, 24.0]);
+        assert_eq!(style.item_inner_spacing, [26.0, 28.0]);
+        assert_eq!(style.touch_extra_padding, [30.0, 32.0]);
+        assert_eq!(style.indent_spacing, 34.0);
+        assert_eq!(style.columns_min_spacing, 36.0);
+        assert_eq!(style.scrollbar_size, 38.0);
+        assert_eq!(style.scrollbar_rounding, 40.0);
+        assert_eq!(style.grab_min_size, 42.0);
+        assert_eq!(style.grab_rounding, 44.0);
+        assert_eq!(style.log_slider_deadzone, 58.0);
+        assert_eq!(style.tab_rounding, 46.0);
+        assert_eq!(style.display_window_padding, [48.0, 50.0]);
+        assert_eq!(style.display_safe_area_padding, [52.0, 54.0]);
+        assert_eq!(style.mouse_cursor_scale, 56.0);
+        assert_eq!(style.cell_padding, [58.0, 60.0]);
+    }
+
+    #[test]
+    fn test_style_color_indexing() {
+        let (_guard, ctx) = crate::test::test_ctx();
+        let mut style = *ctx.style();
+        let value = [0.1, 0.2, 0.3, 1.0];
+        style[StyleColor::Tab] = value;
+        assert_eq!(style[StyleColor::Tab], value);
+        assert_eq!(style.colors[StyleColor::Tab as usize], value);
+    }
+
+    #[test]
+    fn test_style_set_rtl() {
+        let mut style = Style::default();
+        style.set_rtl(true);
+        assert_eq!(style.color_button_position, Direction::Left);
+        assert_eq!(style.window_menu_button_position, Direction::Right);
+        assert_eq!(style.window_title_align, [1.0, 0.5]);
+
+        // already mirrored, so nothing changes
+        style.set_rtl(true);
+        assert_eq!(style.color_button_position, Direction::Left);
+
+        style.set_rtl(false);
+        assert_eq!(style, Style::default());
+    }
+
+    #[test]
+    fn test_style_interactive_colors() {
+        let style = Style::default();
+        let expected = [
+            style[StyleColor::Button],
+            style[StyleColor::ButtonHovered],
+            style[StyleColor::ButtonActive],
+        ];
+        assert_eq!(style.interactive_colors(StyleColor::Button), Some(expected));
+        assert_eq!(
+            style.interactive_colors(StyleColor::ButtonActive),
+            Some(expected)
+        );
+        assert_eq!(style.interactive_colors(StyleColor::Text), None);
+    }
+
+    #[test]
+    fn test_style_diff() {
+        let base = Style::default();
+        assert!(base.diff(&base).is_empty());
+
+        let mut style = base;
+        style.frame_rounding = 4.0;
+        style[StyleColor::Button] = [1.0, 0.0, 0.0, 1.0];
+        let diff = style.diff(&base);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(diff.entries()[0].key, StyleKey::Field("frame_rounding"));
+        assert_eq!(diff.entries()[1].key, StyleKey::Color(StyleColor::Button));
+
+        let mut table = String::new();
+        diff.fmt_table(&mut table).unwrap();
+        assert_eq!(table.lines().next(), Some("frame_rounding: 0 -> 4"));
+    }
+
+    #[test]
+    fn test_show_style_diff() {
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        let ui = ctx.new_frame();
+        assert_eq!(ui.show_style_diff(&Style::default()), 0);
+        ctx.render();
+
+        ctx.style_mut().frame_rounding = 3.0;
+        ctx.style_mut()[StyleColor::Button] = [1.0, 0.0, 0.0, 1.0];
+        let ui = ctx.new_frame();
+        assert_eq!(ui.show_style_diff(&Style::default()), 2);
+    }
+
+    #[test]
+    fn test_style_theme_tokens() {
+        let tokens = ThemeTokens {
+            primary: [0.8, 0.3, 0.1, 1.0],
+            surface: [0.1, 0.1, 0.12, 1.0],
+            on_surface: [0.9, 0.9, 0.9, 1.0],
+            error: [1.0, 0.0, 0.0, 1.0],
+        };
+        let style = Style::from_tokens(tokens);
+        assert_eq!(style[StyleColor::Button][..3], tokens.primary[..3]);
+        assert_eq!(style[StyleColor::ButtonHovered], tokens.primary);
+        assert_eq!(style[StyleColor::WindowBg], tokens.surface);
+        assert_eq!(style.to_tokens(), tokens);
+    }
+
+    #[test]
+    fn test_style_separator_text_layout() {
+        let style = Style::default();
+        let layout = style.separator_text_layout([50.0, 13.0], 200.0);
+        let line_len = |line: [[f32; 2]; 2]| line[1][0] - line[0][0];
+
+        assert_eq!(layout.size, [200.0, 19.0]);
+        assert_eq!(layout.text_min, [20.0, 3.0]);
+        assert_eq!(layout.text_max, [70.0, 16.0]);
+        // padding minus item spacing before the text, the rest after it
+        assert_eq!(line_len(layout.left_line), 12.0);
+        assert_eq!(line_len(layout.right_line), 122.0);
+        assert_eq!(layout.left_line[1][1] - layout.left_line[0][1], 3.0);
+    }
+
+    #[test]
+    fn test_palette_sequencer() {
+        let dark = StyleColor::dark_colors();
+        let light = StyleColor::light_colors();
+        let classic = StyleColor::classic_colors();
+        let mut sequencer = PaletteSequencer::new(dark);
+        sequencer.push(light, 1.0);
+        sequencer.push(classic, 1.0);
+
+        let halfway = sequencer.update(0.5);
+        assert_eq!(halfway[0], lerp(dark[0], light[0], 0.5));
+        assert_eq!(sequencer.update(0.5), light);
+        assert_eq!(sequencer.remaining(), 1);
+        assert_eq!(sequencer.update(5.0), classic);
+        assert_eq!(sequencer.remaining(), 0);
+        assert_eq!(sequencer.update(1.0), classic);
+    }
+
+    #[test]
+    fn test_style_frame_height() {
+        let style = Style {
+            frame_padding: [4.0, 5.0],
+            ..Default::default()
+        };
+        assert_eq!(style.frame_height(13.0), 13.0 + 5.0 * 2.0);
+    }
+
+    #[test]
+    fn test_style_title_bar_height() {
+        let style = Style {
+            frame_padding: [4.0, 5.0],
+            ..Default::default()
+        };
+        assert_eq!(style.title_bar_height(13.0), 23.0);
+    }
+
+    #[test]
+    fn test_style_combo_popup_height() {
+        let style = Style::default();
+        // font size 13, item spacing 4, window padding 8
+        assert_eq!(style.combo_popup_height(0, 8, 13.0), 16.0);
+        assert_eq!(style.combo_popup_height(1, 8, 13.0), 29.0);
+        assert_eq!(style.combo_popup_height(3, 8, 13.0), 63.0);
+        assert_eq!(
+            style.combo_popup_height(100, 8, 13.0),
+            style.combo_popup_height(8, 8, 13.0)
+        );
+    }
+
+    #[test]
+    fn test_style_composite_over_bg() {
+        let mut style = Style::default();
+        style[StyleColor::WindowBg] = [0.0, 0.0, 0.0, 0.5];
+        style[StyleColor::Button] = [1.0, 1.0, 1.0, 0.5];
+        assert_eq!(
+            style.composite_over_bg(StyleColor::Button),
+            [0.5, 0.5, 0.5, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_window_draw_flags() {
+        let square = Style {
+            window_rounding: 0.0,
+            ..Style::default()
+        };
+        assert_eq!(
+            square.window_draw_flags(),
+            (0.0, DrawFlags::ROUND_CORNERS_NONE)
+        );
+        let round = Style {
+            window_rounding: 6.0,
+            ..Style::default()
+        };
+        assert_eq!(
+            round.window_draw_flags(),
+            (6.0, DrawFlags::ROUND_CORNERS_ALL)
+        );
+    }
+
+    #[test]
+    fn test_tab_size() {
+        let style = Style {
+            frame_padding: [4.0, 3.0],
+            item_inner_spacing: [4.0, 4.0],
+            ..Style::default()
+        };
+        assert_eq!(style.tab_size([40.0, 13.0], false, 13.0), [49.0, 19.0]);
+        assert_eq!(style.tab_size([40.0, 13.0], true, 13.0), [65.0, 19.0]);
+    }
+
+    #[test]
+    fn test_rehue() {
+        let mut style = Style::default();
+        style[StyleColor::Button] = [1.0, 0.0, 0.0, 0.5];
+        style[StyleColor::Text] = [0.5, 0.5, 0.5, 1.0];
+        style.rehue(120.0);
+
+        let [r, g, b, a] = style[StyleColor::Button];
+        assert!(r.abs() < 1e-5 && (g - 1.0).abs() < 1e-5 && b.abs() < 1e-5);
+        assert_eq!(a, 0.5);
+        assert_eq!(style[StyleColor::Text], [0.5, 0.5, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_rounding_for() {
+        let style = Style {
+            window_rounding: 3.0,
+            popup_rounding: 7.0,
+            ..Style::default()
+        };
+        assert_eq!(style.rounding_for(SurfaceKind::Tooltip), 3.0);
+        assert_eq!(style.rounding_for(SurfaceKind::Popup), 7.0);
+    }
+
+    #[test]
+    fn test_apply_kv() {
+        let mut style = Style::default();
+        style.apply_kv("frame_rounding", "4.0").unwrap();
+        assert_eq!(style.frame_rounding, 4.0);
+
+        style.apply_kv("window_padding", "8, 6").unwrap();
+        assert_eq!(style.window_padding, [8.0, 6.0]);
+
+        style.apply_kv("colors.Button", "#3377ffcc").unwrap();
+        assert_eq!(
+            style[StyleColor::Button],
+            [
+                0x33 as f32 / 255.0,
+                0x77 as f32 / 255.0,
+                1.0,
+                0xcc as f32 / 255.0
+            ]
+        );
+
+        assert_eq!(
+            style.apply_kv("frame_roundin", "4.0"),
+            Err(StyleKvError::UnknownKey("frame_roundin".into()))
+        );
+        assert_eq!(
+            style.apply_kv("colors.Button", "3377ff"),
+            Err(StyleKvError::InvalidValue {
+                key: "colors.Button".into(),
+                value: "3377ff".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_status_colors() {
+        let style = Style::default();
+        let defaults = StatusColors::from_style(&style);
+        for kind in [
+            StatusKind::Success,
+            StatusKind::Warning,
+            StatusKind::Error,
+            StatusKind::Info,
+        ] {
+            assert_eq!(defaults.get(kind)[3], 1.0);
+        }
+        let [r, g, b, _] = defaults.error;
+        assert!(r > g && r > b);
+        let hue = |[r, g, b, _]: [f32; 4]| rgb_to_hsl([r, g, b])[0];
+        assert!((hue(defaults.info) - hue(style[StyleColor::CheckMark])).abs() < 1e-4);
+
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        let custom = StatusColors {
+            error: [1.0, 0.0, 1.0, 1.0],
+            ..defaults
+        };
+        ctx.style_extras_mut().status_colors = Some(custom);
+        let ui = ctx.new_frame();
+        assert_eq!(ui.status_color(StatusKind::Error), [1.0, 0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_status_colors_serde() {
+        let colors = StatusColors::from_style(&Style::default());
+        let json = serde_json::to_string(&colors).unwrap();
+        assert_eq!(serde_json::from_str::<StatusColors>(&json).unwrap(), colors);
+    }
+
+    #[test]
+    fn test_unaffected_by_disabled_alpha() {
+        let colors = StyleColor::unaffected_by_disabled_alpha();
+        assert!(!colors.is_empty());
+        assert!(colors.contains(&StyleColor::ModalWindowDimBg));
+        assert!(colors.contains(&StyleColor::NavWindowingDimBg));
+    }
+
+    #[test]
+    fn test_slider_grab() {
+        let style = Style {
+            grab_min_size: 12.0,
+            ..Style::default()
+        };
+        assert_eq!(style.slider_grab(104.0, 0.0), (2.0, 12.0));
+        assert_eq!(style.slider_grab(104.0, 1.0), (90.0, 12.0));
+        for ratio in [0.0, 0.3, 0.5, 1.0] {
+            let (pos, size) = style.slider_grab(104.0, ratio);
+            assert!(size >= style.grab_min_size);
+            assert!(pos + size <= 102.0);
+        }
+    }
+
+    #[test]
+    fn test_menu_columns() {
+        let style = Style {
+            item_spacing: [8.0, 4.0],
+            ..Style::default()
+        };
+        assert_eq!(style.menu_columns(60.0, 40.0), (108.0, 68.0));
+        assert_eq!(style.menu_columns(60.0, 0.0), (60.0, 68.0));
+    }
+
+    #[test]
+    fn test_invert_palette() {
+        let colors = StyleColor::light_colors();
+        let close = |a: &[[f32; 4]; StyleColor::COUNT], b: &[[f32; 4]; StyleColor::COUNT]| {
+            a.iter()
+                .flatten()
+                .zip(b.iter().flatten())
+                .all(|(x, y)| (x - y).abs() < 1e-4)
+        };
+
+        let inverted = StyleColor::invert_palette(&colors);
+        assert_eq!(inverted[StyleColor::Text as usize], [1.0, 1.0, 1.0, 1.0]);
+        assert!(close(&StyleColor::invert_palette(&inverted), &colors));
+
+        let inverted = StyleColor::invert_palette_preserving_hue(&colors);
+        assert!(close(
+            &StyleColor::invert_palette_preserving_hue(&inverted),
+            &colors
+        ));
+    }
+
+    #[test]
+    fn test_progress_bar_fill_rect() {
+        let style = Style {
+            frame_border_size: 1.0,
+            ..Style::default()
+        };
+        assert_eq!(
+            style.progress_bar_fill_rect([10.0, 10.0], [112.0, 30.0], 0.5),
+            ([11.0, 11.0], [61.0, 29.0])
+        );
+        assert_eq!(
+            style.progress_bar_fill_rect([10.0, 10.0], [112.0, 30.0], 2.0),
+            ([11.0, 11.0], [111.0, 29.0])
+        );
+    }
+
+    #[test]
+    fn test_style_cache() {
+        let mut cache = StyleCache::new();
+        let mut style = Style::default();
+        let mut runs = 0;
+        let mut compute = |style: &Style| {
+            runs += 1;
+            style.frame_rounding
+        };
+        assert_eq!(
+            *cache.get_or_compute(&style, &mut compute),
+            style.frame_rounding
+        );
+        assert_eq!(
+            *cache.get_or_compute(&style, &mut compute),
+            style.frame_rounding
+        );
+        style.frame_rounding = 5.0;
+        assert_eq!(*cache.get_or_compute(&style, &mut compute), 5.0);
+        assert_eq!(runs, 2);
+    }
+
+    #[test]
+    fn test_icon_label_width() {
+        let style = Style {
+            item_inner_spacing: [6.0, 4.0],
+            ..Style::default()
+        };
+        assert_eq!(style.icon_label_width(16.0, 50.0), 72.0);
+    }
+
+    #[test]
+    fn test_drag_drop_target_animated() {
+        let style = Style::default();
+        let [r, g, b, a] = style[StyleColor::DragDropTarget];
+        let alphas: Vec<f32> = (0..20)
+            .map(|i| {
+                let color = style.drag_drop_target_animated(i as f32 * 0.05);
+                assert_eq!(&color[..3], &[r, g, b]);
+                color[3]
+            })
+            .collect();
+        assert!(alphas
+            .iter()
+            .all(|&alpha| alpha >= a * 0.5 - 1e-6 && alpha <= a + 1e-6));
+        assert!((alphas[0] - a).abs() < 1e-6);
+        assert!((alphas[10] - a * 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_set_all_borders() {
+        let mut style = Style::default();
+        style.set_all_borders(1.0);
+        assert_eq!(style.window_border_size, 1.0);
+        assert_eq!(style.child_border_size, 1.0);
+        assert_eq!(style.popup_border_size, 1.0);
+        assert_eq!(style.frame_border_size, 1.0);
+        assert_eq!(style.tab_border_size, 1.0);
+        assert_eq!(style.separator_text_border_size, 1.0);
+    }
+
+    #[test]
+    fn test_tree_node_layout() {
+        let style = Style {
+            frame_padding: [4.0, 3.0],
+            indent_spacing: 21.0,
+            ..Style::default()
+        };
+        let layout = style.tree_node_layout(13.0, [40.0, 13.0]);
+        assert_eq!(layout.arrow, [[4.0, 3.0], [17.0, 16.0]]);
+        assert_eq!(layout.label_pos, [25.0, 3.0]);
+        assert_eq!(layout.frame_height, 19.0);
+        assert_eq!(layout.child_indent, 21.0);
+    }
+
+    #[test]
+    fn test_interpolate_to_alert() {
+        let alert = [[1.0, 0.0, 0.0, 1.0]; StyleColor::COUNT];
+        let base = Style::default();
+
+        let mut style = base;
+        style.interpolate_to_alert(0.0, &alert);
+        assert_eq!(style.colors, base.colors);
+
+        let mut style = base;
+        style.interpolate_to_alert(1.0, &alert);
+        assert_eq!(style.colors, alert);
+
+        let mut style = base;
+        style.interpolate_to_alert(3.0, &alert);
+        assert_eq!(style.colors, alert);
+    }
+
+    #[test]
+    fn test_roundtrip_through_sys() {
+        let style = Style::default();
+        assert_eq!(style.roundtrip_through_sys(), style);
+
+        let style = Style {
+            window_border_hover_padding: 6.0,
+            frame_rounding: 3.0,
+            tab_close_button_min_width_selected: 0.0,
+            color_button_position: Direction::Left,
+            ..Style::default()
+        };
+        let raw = unsafe { &*(&style as *const Style as *const sys::ImGuiStyle) };
+        assert_eq!(raw.WindowBorderHoverPadding, 6.0);
+        assert_eq!(raw.FrameRounding, 3.0);
+        assert_eq!(raw.TabCloseButtonMinWidthSelected, 0.0);
+        assert_eq!(
+            raw.Colors[StyleColor::Button as usize].w,
+            style[StyleColor::Button][3]
+        );
+        assert_eq!(style.roundtrip_through_sys(), style);
+    }
+
+    #[test]
+    fn test_derive_nav_from_accent() {
+        let mut style = Style::default();
+        style[StyleColor::HeaderActive] = [0.1, 0.8, 0.3, 0.6];
+        style.derive_nav_from_accent();
+        assert_eq!(style[StyleColor::NavCursor], [0.1, 0.8, 0.3, 1.0]);
+        assert_eq!(
+            style[StyleColor::NavWindowingHighlight],
+            [0.1, 0.8, 0.3, 0.7]
         );
-        colors[Self::TabSelected as usize] = lerp(
-            colors[Self::HeaderActive as usize],
-            colors[Self::TitleBgActive as usize],
-            0.60,
+    }
+
+    #[test]
+    fn test_export_style_patch() {
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        let ui = ctx.new_frame();
+        assert_eq!(ui.export_style_patch(&Style::default()), "{}");
+        ctx.render();
+
+        ctx.style_mut().frame_rounding = 4.0;
+        let ui = ctx.new_frame();
+        assert_eq!(
+            ui.export_style_patch(&Style::default()),
+            r#"{"frame_rounding":4}"#
         );
-        colors[Self::TabSelectedOverline as usize] = colors[Self::HeaderActive as usize];
-        colors[Self::TabDimmed as usize] = lerp(
-            colors[Self::Tab as usize],
-            colors[Self::TitleBg as usize],
-            0.80,
+        ctx.render();
+
+        let base = Style::default();
+        let mut style = base;
+        style.frame_rounding = 4.0;
+        style.window_padding = [8.0, 6.5];
+        style[StyleColor::Button] = [1.0, 0.0, 0.5, 1.0];
+        let mut json = String::new();
+        style.diff(&base).write_json(&mut json).unwrap();
+        assert_eq!(
+            json,
+            r#"{"window_padding":[8,6.5],"frame_rounding":4,"colors.Button":[1,0,0.5,1]}"#
         );
-        colors[Self::TabDimmedSelected as usize] = lerp(
-            colors[Self::TabSelected as usize],
-            colors[Self::TitleBg as usize],
-            0.40,
+    }
+
+    #[test]
+    fn test_resize_grip_points() {
+        let style = Style {
+            window_border_size: 1.0,
+            window_rounding: 6.0,
+            ..Style::default()
+        };
+        let corner = [200.0, 100.0];
+        let size = 14.0;
+        for [x, y] in style.resize_grip_points(corner, size) {
+            assert!(x >= corner[0] - size && x <= corner[0]);
+            assert!(y >= corner[1] - size && y <= corner[1]);
+        }
+        let square = Style {
+            window_rounding: 0.0,
+            ..style
+        };
+        assert_eq!(
+            square.resize_grip_points(corner, size),
+            [[186.0, 99.0], [199.0, 86.0], [199.0, 99.0]]
         );
-        colors[Self::TabDimmedSelectedOverline as usize] = [0.50, 0.50, 0.50, 1.00];
-        colors[Self::PlotLines as usize] = [0.61, 0.61, 0.61, 1.00];
-        colors[Self::PlotLinesHovered as usize] = [1.00, 0.43, 0.35, 1.00];
-        colors[Self::PlotHistogram as usize] = [0.90, 0.70, 0.00, 1.00];
-        colors[Self::PlotHistogramHovered as usize] = [1.00, 0.60, 0.00, 1.00];
-        colors[Self::TableHeaderBg as usize] = [0.19, 0.19, 0.20, 1.00];
-        colors[Self::TableBorderStrong as usize] = [0.31, 0.31, 0.35, 1.00];
-        colors[Self::TableBorderLight as usize] = [0.23, 0.23, 0.25, 1.00];
-        colors[Self::TableRowBg as usize] = [0.00, 0.00, 0.00, 0.00];
-        colors[Self::TableRowBgAlt as usize] = [1.00, 1.00, 1.00, 0.06];
-        colors[Self::TextLink as usize] = colors[Self::HeaderActive as usize];
-        colors[Self::TextSelectedBg as usize] = [0.26, 0.59, 0.98, 0.35];
-        colors[Self::DragDropTarget as usize] = [1.00, 1.00, 0.00, 0.90];
-        colors[Self::NavCursor as usize] = [0.26, 0.59, 0.98, 1.00];
-        colors[Self::NavWindowingHighlight as usize] = [1.00, 1.00, 1.00, 0.70];
-        colors[Self::NavWindowingDimBg as usize] = [0.80, 0.80, 0.80, 0.20];
-        colors[Self::ModalWindowDimBg as usize] = [0.80, 0.80, 0.80, 0.35];
+    }
 
-        #[cfg(feature = "docking")]
-        {
-            colors[Self::DockingPreview as usize] = std::array::from_fn(|idx| {
-                let multiplier = if idx == 3 { 0.7 } else { 1.0 };
+    #[test]
+    fn test_muted() {
+        let mut style = Style::default();
+        style[StyleColor::Button] = [0.2, 0.4, 0.9, 0.8];
+        let unchanged = style.muted(StyleColor::Button, 0.0);
+        for (x, y) in unchanged.iter().zip([0.2, 0.4, 0.9, 0.8]) {
+            assert!((x - y).abs() < 1e-5);
+        }
 
-                colors[Self::HeaderActive as usize][idx] * multiplier
-            });
+        let [r, g, b, a] = style.muted(StyleColor::Button, 1.0);
+        let lightness = (0.2 + 0.9) / 2.0;
+        for channel in [r, g, b] {
+            assert!((channel - lightness).abs() < 1e-5);
+        }
+        assert_eq!(a, 0.8);
+    }
 
-            colors[Self::DockingEmptyBg as usize] = [0.2, 0.2, 0.2, 1.0];
+    #[test]
+    fn test_theme_toggle() {
+        let dark = Style::default();
+        let light = Style {
+            colors: StyleColor::light_colors(),
+            ..Style::default()
+        };
+
+        let mut toggle = ThemeToggle::new(dark, light);
+        assert_eq!(toggle.toggle(), &light);
+        assert_eq!(toggle.update(0.1), light);
+        assert_eq!(toggle.toggle(), &dark);
+        assert_eq!(toggle.update(0.1), dark);
+
+        let mut toggle = ThemeToggle::new(dark, light).with_crossfade(1.0);
+        toggle.toggle();
+        let halfway = toggle.update(0.5);
+        assert_ne!(halfway.colors, dark.colors);
+        assert_ne!(halfway.colors, light.colors);
+        assert_eq!(toggle.update(0.5), light);
+    }
+
+    #[test]
+    fn test_quantize_palette() {
+        let colors = StyleColor::dark_colors();
+        for max_colors in [0, 1, 4, 16, StyleColor::COUNT] {
+            let (remapped, swatches) = StyleColor::quantize_palette(&colors, max_colors);
+            assert!(swatches.len() <= max_colors.max(1));
+            assert!(remapped.iter().all(|color| swatches.contains(color)));
         }
+    }
 
-        colors
+    #[test]
+    fn test_child_content_inset() {
+        let style = Style {
+            window_padding: [8.0, 8.0],
+            child_border_size: 1.0,
+            scrollbar_size: 14.0,
+            ..Style::default()
+        };
+        assert_eq!(style.child_content_inset(false, false), [18.0, 18.0]);
+        assert_eq!(style.child_content_inset(true, false), [32.0, 18.0]);
+        assert_eq!(style.child_content_inset(true, true), [32.0, 32.0]);
     }
 
-    /// Returns the "Light" style colors for ImGui as an array.
-    ///
-    /// You can set this output to [`Style::colors`] to change the style palette.
-    pub fn light_colors() -> [[f32; 4]; StyleColor::COUNT] {
-        let mut colors = [Default::default(); StyleColor::COUNT];
+    #[test]
+    fn test_style_color_name_table() {
+        let table = StyleColor::NAME_TABLE;
+        assert!(table.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(table.len(), StyleColor::VARIANTS.len());
+        for color in StyleColor::VARIANTS {
+            assert!(table.contains(&(color.name(), color)));
+            assert_eq!(StyleColor::from_name(color.name()), Some(color));
+        }
+        assert_eq!(StyleColor::from_name("Nope"), None);
+        assert_eq!(StyleColor::from_name("windowbg"), None);
+        #[cfg(not(feature = "docking"))]
+        assert_eq!(StyleColor::from_name("DockingPreview"), None);
+    }
 
-        colors[Self::Text as usize] = [0.00, 0.00, 0.00, 1.00];
-        colors[Self::TextDisabled as usize] = [0.60, 0.60, 0.60, 1.00];
-        colors[Self::WindowBg as usize] = [0.94, 0.94, 0.94, 1.00];
-        colors[Self::ChildBg as usize] = [0.00, 0.00, 0.00, 0.00];
-        colors[Self::PopupBg as usize] = [1.00, 1.00, 1.00, 0.98];
-        colors[Self::Border as usize] = [0.00, 0.00, 0.00, 0.30];
-        colors[Self::BorderShadow as usize] = [0.00, 0.00, 0.00, 0.00];
-        colors[Self::FrameBg as usize] = [1.00, 1.00, 1.00, 1.00];
-        colors[Self::FrameBgHovered as usize] = [0.26, 0.59, 0.98, 0.40];
-        colors[Self::FrameBgActive as usize] = [0.26, 0.59, 0.98, 0.67];
-        colors[Self::TitleBg as usize] = [0.96, 0.96, 0.96, 1.00];
-        colors[Self::TitleBgActive as usize] = [0.82, 0.82, 0.82, 1.00];
-        colors[Self::TitleBgCollapsed as usize] = [1.00, 1.00, 1.00, 0.51];
-        colors[Self::MenuBarBg as usize] = [0.86, 0.86, 0.86, 1.00];
-        colors[Self::ScrollbarBg as usize] = [0.98, 0.98, 0.98, 0.53];
-        colors[Self::ScrollbarGrab as usize] = [0.69, 0.69, 0.69, 0.80];
-        colors[Self::ScrollbarGrabHovered as usize] = [0.49, 0.49, 0.49, 0.80];
-        colors[Self::ScrollbarGrabActive as usize] = [0.49, 0.49, 0.49, 1.00];
-        colors[Self::CheckMark as usize] = [0.26, 0.59, 0.98, 1.00];
-        colors[Self::SliderGrab as usize] = [0.26, 0.59, 0.98, 0.78];
-        colors[Self::SliderGrabActive as usize] = [0.46, 0.54, 0.80, 0.60];
-        colors[Self::Button as usize] = [0.26, 0.59, 0.98, 0.40];
-        colors[Self::ButtonHovered as usize] = [0.26, 0.59, 0.98, 1.00];
-        colors[Self::ButtonActive as usize] = [0.06, 0.53, 0.98, 1.00];
-        colors[Self::Header as usize] = [0.26, 0.59, 0.98, 0.31];
-        colors[Self::HeaderHovered as usize] = [0.26, 0.59, 0.98, 0.80];
-        colors[Self::HeaderActive as usize] = [0.26, 0.59, 0.98, 1.00];
-        colors[Self::Separator as usize] = [0.39, 0.39, 0.39, 0.62];
-        colors[Self::SeparatorHovered as usize] = [0.14, 0.44, 0.80, 0.78];
-        colors[Self::SeparatorActive as usize] = [0.14, 0.44, 0.80, 1.00];
-        colors[Self::ResizeGrip as usize] = [0.35, 0.35, 0.35, 0.17];
-        colors[Self::ResizeGripHovered as usize] = [0.26, 0.59, 0.98, 0.67];
-        colors[Self::ResizeGripActive as usize] = [0.26, 0.59, 0.98, 0.95];
-        colors[Self::TabHovered as usize] = colors[Self::HeaderHovered as usize];
-        colors[Self::Tab as usize] = lerp(
-            colors[Self::Header as usize],
-            colors[Self::TitleBgActive as usize],
-            0.90,
+    #[test]
+    fn test_style_diff_with_epsilon_and_apply() {
+        let base = Style::default();
+        let mut style = base;
+        style.alpha = base.alpha - 1e-6;
+        style.frame_rounding = 4.0;
+        style.window_menu_button_position = Direction::Right;
+        style[StyleColor::Text] = [0.5, 0.5, 0.5, 1.0];
+
+        assert_eq!(style.diff(&base).len(), 4);
+        let diff = style.diff_with_epsilon(&base, 1e-4);
+        assert_eq!(diff.len(), 3);
+        assert!(diff
+            .entries()
+            .iter()
+            .all(|entry| entry.key != StyleKey::Field("alpha")));
+
+        let mut target = base;
+        diff.apply(&mut target);
+        assert_eq!(target.frame_rounding, 4.0);
+        assert_eq!(target.window_menu_button_position, Direction::Right);
+        assert_eq!(target[StyleColor::Text], [0.5, 0.5, 0.5, 1.0]);
+        assert_eq!(target.alpha, base.alpha);
+        assert!(style.diff_with_epsilon(&target, 1e-4).is_empty());
+    }
+
+    #[test]
+    fn test_style_lerp() {
+        let a = Style {
+            alpha: 0.2,
+            window_padding: [4.0, 4.0],
+            anti_aliased_lines: true,
+            ..Style::default()
+        };
+        let b = Style {
+            alpha: 0.8,
+            window_padding: [8.0, 12.0],
+            anti_aliased_lines: false,
+            colors: StyleColor::light_colors(),
+            ..Style::default()
+        };
+        assert_eq!(Style::lerp(&a, &b, 0.0), a);
+        assert_eq!(Style::lerp(&a, &b, 1.0), b);
+        assert_eq!(Style::lerp(&a, &b, -1.0), a);
+
+        let mid = Style::lerp(&a, &b, 0.5);
+        assert!((mid.alpha - 0.5).abs() < 1e-6);
+        assert_eq!(mid.window_padding, [6.0, 8.0]);
+        assert!(!mid.anti_aliased_lines);
+        assert!(Style::lerp(&a, &b, 0.25).anti_aliased_lines);
+    }
+
+    #[test]
+    fn test_lerp_palettes_linear() {
+        let black = [[0.0, 0.0, 0.0, 1.0]; StyleColor::COUNT];
+        let white = [[1.0, 1.0, 1.0, 1.0]; StyleColor::COUNT];
+
+        let srgb = StyleColor::lerp_palettes(&black, &white, 0.5);
+        let linear = StyleColor::lerp_palettes_linear(&black, &white, 0.5);
+        assert_eq!(srgb[0], [0.5, 0.5, 0.5, 1.0]);
+        // half the light of white is lighter than the sRGB midpoint
+        assert!(linear[0][0] > 0.7 && linear[0][0] < 0.75);
+        assert_eq!(linear[0][3], 1.0);
+
+        assert_eq!(StyleColor::lerp_palettes_linear(&black, &white, 0.0), black);
+        assert_eq!(StyleColor::lerp_palettes_linear(&black, &white, 1.0), white);
+    }
+
+    #[test]
+    fn test_tooltip_offset() {
+        let style = Style {
+            mouse_cursor_scale: 1.0,
+            ..Style::default()
+        };
+        assert_eq!(style.tooltip_offset(), [16.0, 10.0]);
+        let scaled = Style {
+            mouse_cursor_scale: 2.0,
+            ..style
+        };
+        assert_eq!(scaled.tooltip_offset(), [32.0, 20.0]);
+    }
+
+    #[test]
+    fn test_style_get_set_var() {
+        let mut style = Style {
+            frame_padding: [4.0, 3.0],
+            frame_rounding: 2.0,
+            ..Style::default()
+        };
+        assert_eq!(
+            style.get_var(StyleVar::FramePadding([0.0, 0.0])),
+            StyleVar::FramePadding([4.0, 3.0])
         );
-        colors[Self::TabSelected as usize] = lerp(
-            colors[Self::HeaderActive as usize],
-            colors[Self::TitleBgActive as usize],
-            0.60,
+        assert_eq!(
+            style.get_var(StyleVar::FrameRounding(0.0)),
+            StyleVar::FrameRounding(2.0)
         );
-        colors[Self::TabSelectedOverline as usize] = colors[Self::HeaderActive as usize];
-        colors[Self::TabDimmed as usize] = lerp(
-            colors[Self::Tab as usize],
-            colors[Self::TitleBg as usize],
-            0.80,
+
+        style.set_var(StyleVar::ItemSpacing([9.0, 7.0]));
+        style.set_var(StyleVar::GrabRounding(5.0));
+        assert_eq!(style.item_spacing, [9.0, 7.0]);
+        assert_eq!(style.grab_rounding, 5.0);
+        assert_eq!(
+            style.get_var(StyleVar::ItemSpacing([0.0, 0.0])),
+            StyleVar::ItemSpacing([9.0, 7.0])
         );
-        colors[Self::TabDimmedSelected as usize] = lerp(
-            colors[Self::TabSelected as usize],
-            colors[Self::TitleBg as usize],
-            0.40,
+    }
+
+    #[test]
+    fn test_color_hex() {
+        assert_eq!(color_from_hex("#ff0000"), Ok([1.0, 0.0, 0.0, 1.0]));
+        assert_eq!(
+            color_from_hex("00FF0080"),
+            Ok([0.0, 1.0, 0.0, 128.0 / 255.0])
         );
-        colors[Self::TabDimmedSelectedOverline as usize] = [0.26, 0.59, 1.00, 1.00];
-        colors[Self::PlotLines as usize] = [0.39, 0.39, 0.39, 1.00];
-        colors[Self::PlotLinesHovered as usize] = [1.00, 0.43, 0.35, 1.00];
-        colors[Self::PlotHistogram as usize] = [0.90, 0.70, 0.00, 1.00];
-        colors[Self::PlotHistogramHovered as usize] = [1.00, 0.45, 0.00, 1.00];
-        colors[Self::TableHeaderBg as usize] = [0.78, 0.87, 0.98, 1.00];
-        colors[Self::TableBorderStrong as usize] = [0.57, 0.57, 0.64, 1.00];
-        colors[Self::TableBorderLight as usize] = [0.68, 0.68, 0.74, 1.00];
-        colors[Self::TableRowBg as usize] = [0.00, 0.00, 0.00, 0.00];
-        colors[Self::TableRowBgAlt as usize] = [0.30, 0.30, 0.30, 0.09];
-        colors[Self::TextLink as usize] = colors[Self::HeaderActive as usize];
-        colors[Self::TextSelectedBg as usize] = [0.26, 0.59, 0.98, 0.35];
-        colors[Self::DragDropTarget as usize] = [0.26, 0.59, 0.98, 0.95];
-        colors[Self::NavCursor as usize] = colors[Self::HeaderHovered as usize];
-        colors[Self::NavWindowingHighlight as usize] = [0.70, 0.70, 0.70, 0.70];
-        colors[Self::NavWindowingDimBg as usize] = [0.20, 0.20, 0.20, 0.20];
-        colors[Self::ModalWindowDimBg as usize] = [0.20, 0.20, 0.20, 0.35];
+        assert_eq!(color_from_hex("#aBc"), color_from_hex("#aabbcc"));
+        assert_eq!(color_from_hex("#0f08"), color_from_hex("#00ff0088"));
+        assert_eq!(
+            color_from_hex("#12345"),
+            Err(ColorParseError::InvalidLength(5))
+        );
+        assert_eq!(
+            color_from_hex("#12g456"),
+            Err(ColorParseError::InvalidDigit('g'))
+        );
+        assert_eq!(color_from_hex(""), Err(ColorParseError::InvalidLength(0)));
 
-        #[cfg(feature = "docking")]
-        {
-            colors[Self::DockingPreview as usize] = std::array::from_fn(|idx| {
-                let multiplier = if idx == 3 { 0.7 } else { 1.0 };
+        assert_eq!(color_to_hex([1.0, 0.2, 0.0, 0.5], false), "#FF3300");
+        assert_eq!(color_to_hex([1.0, 0.2, 0.0, 0.5], true), "#FF330080");
+        let color = color_from_hex("#3377ffcc").unwrap();
+        assert_eq!(color_to_hex(color, true), "#3377FFCC");
+    }
 
-                colors[Self::Header as usize][idx] * multiplier
-            });
+    #[test]
+    fn test_load_palette_lenient() {
+        let short = [[0.5, 0.5, 0.5, 1.0]; 10];
+        let (palette, defaulted) = StyleColor::load_palette_lenient(&short);
+        assert_eq!(defaulted, StyleColor::COUNT - 10);
+        assert_eq!(&palette[..10], &short[..]);
+        assert_eq!(&palette[10..], &StyleColor::dark_colors()[10..]);
 
-            colors[Self::DockingEmptyBg as usize] = [0.2, 0.2, 0.2, 1.0];
+        let long = vec![[1.0; 4]; StyleColor::COUNT + 5];
+        let (palette, defaulted) = StyleColor::load_palette_lenient(&long);
+        assert_eq!(defaulted, 0);
+        assert_eq!(&palette[..], &long[..StyleColor::COUNT]);
+    }
+
+    #[test]
+    fn test_window_corner_mask() {
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        ctx.style_mut().window_rounding = 6.0;
+        assert_eq!(
+            ctx.style_extras().window_corner_mask,
+            DrawFlags::ROUND_CORNERS_ALL
+        );
+        ctx.style_extras_mut().window_corner_mask = DrawFlags::ROUND_CORNERS_TOP;
+        let ui = ctx.new_frame();
+        assert_eq!(ui.window_draw_flags(), (6.0, DrawFlags::ROUND_CORNERS_TOP));
+    }
+
+    #[test]
+    fn test_iter_colors_mut() {
+        let mut style = Style::default();
+        for (color, value) in style.iter_colors_mut() {
+            *value = [color as usize as f32, 0.0, 0.0, 1.0];
+        }
+        for (i, value) in style.colors.iter().enumerate() {
+            assert_eq!(*value, [i as f32, 0.0, 0.0, 1.0]);
         }
+        assert!(style
+            .iter_colors()
+            .all(|(color, value)| *value == style[color]));
+        assert_eq!(style.iter_colors().count(), StyleColor::COUNT);
+    }
 
-        colors
+    #[test]
+    fn test_disabled_text_color() {
+        let mut style = Style::default();
+        style[StyleColor::TextDisabled] = [0.1, 0.2, 0.3, 0.4];
+        assert_eq!(style.disabled_text_color(), style[StyleColor::TextDisabled]);
     }
 
-    /// Returns the "Classic" style colors for ImGui as an array. ImGui now uses
-    /// the "Dark" style colors, which can be made from [`StyleColor::dark_colors`].
-    ///
-    /// You can set this output to [`Style::colors`] to change the style palette.
-    pub fn classic_colors() -> [[f32; 4]; StyleColor::COUNT] {
-        let mut colors = [Default::default(); StyleColor::COUNT];
+    #[test]
+    fn test_srgb_linear_conversion() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert_eq!(linear_to_srgb(0.0), 0.0);
+        assert!((srgb_to_linear(1.0) - 1.0).abs() < 1e-6);
+        assert!((linear_to_srgb(1.0) - 1.0).abs() < 1e-6);
+        // both sides of the linear segment meet at the threshold
+        assert!((linear_to_srgb(0.0031308) - 0.04045).abs() < 1e-5);
+        assert!((linear_to_srgb(0.0031309) - 0.04045).abs() < 1e-5);
+        assert!((srgb_to_linear(0.04045) - 0.0031308).abs() < 1e-6);
 
-        colors[Self::Text as usize] = [0.90, 0.90, 0.90, 1.00];
-        colors[Self::TextDisabled as usize] = [0.60, 0.60, 0.60, 1.00];
-        colors[Self::WindowBg as usize] = [0.00, 0.00, 0.00, 0.85];
-        colors[Self::ChildBg as usize] = [0.00, 0.00, 0.00, 0.00];
-        colors[Self::PopupBg as usize] = [0.11, 0.11, 0.14, 0.92];
-        colors[Self::Border as usize] = [0.50, 0.50, 0.50, 0.50];
-        colors[Self::BorderShadow as usize] = [0.00, 0.00, 0.00, 0.00];
-        colors[Self::FrameBg as usize] = [0.43, 0.43, 0.43, 0.39];
-        colors[Self::FrameBgHovered as usize] = [0.47, 0.47, 0.69, 0.40];
-        colors[Self::FrameBgActive as usize] = [0.42, 0.41, 0.64, 0.69];
-        colors[Self::TitleBg as usize] = [0.27, 0.27, 0.54, 0.83];
-        colors[Self::TitleBgActive as usize] = [0.32, 0.32, 0.63, 0.87];
-        colors[Self::TitleBgCollapsed as usize] = [0.40, 0.40, 0.80, 0.20];
-        colors[Self::MenuBarBg as usize] = [0.40, 0.40, 0.55, 0.80];
-        colors[Self::ScrollbarBg as usize] = [0.20, 0.25, 0.30, 0.60];
-        colors[Self::ScrollbarGrab as usize] = [0.40, 0.40, 0.80, 0.30];
-        colors[Self::ScrollbarGrabHovered as usize] = [0.40, 0.40, 0.80, 0.40];
-        colors[Self::ScrollbarGrabActive as usize] = [0.41, 0.39, 0.80, 0.60];
-        colors[Self::CheckMark as usize] = [0.90, 0.90, 0.90, 0.50];
-        colors[Self::SliderGrab as usize] = [1.00, 1.00, 1.00, 0.30];
-        colors[Self::SliderGrabActive as usize] = [0.41, 0.39, 0.80, 0.60];
-        colors[Self::Button as usize] = [0.35, 0.40, 0.61, 0.62];
-        colors[Self::ButtonHovered as usize] = [0.40, 0.48, 0.71, 0.79];
-        colors[Self::ButtonActive as usize] = [0.46, 0.54, 0.80, 1.00];
-        colors[Self::Header as usize] = [0.40, 0.40, 0.90, 0.45];
-        colors[Self::HeaderHovered as usize] = [0.45, 0.45, 0.90, 0.80];
-        colors[Self::HeaderActive as usize] = [0.53, 0.53, 0.87, 0.80];
-        colors[Self::Separator as usize] = [0.50, 0.50, 0.50, 0.60];
-        colors[Self::SeparatorHovered as usize] = [0.60, 0.60, 0.70, 1.00];
-        colors[Self::SeparatorActive as usize] = [0.70, 0.70, 0.90, 1.00];
-        colors[Self::ResizeGrip as usize] = [1.00, 1.00, 1.00, 0.10];
-        colors[Self::ResizeGripHovered as usize] = [0.78, 0.82, 1.00, 0.60];
-        colors[Self::ResizeGripActive as usize] = [0.78, 0.82, 1.00, 0.90];
-        colors[Self::TabHovered as usize] = colors[Self::HeaderHovered as usize];
-        colors[Self::Tab as usize] = lerp(
-            colors[Self::Header as usize],
-            colors[Self::TitleBgActive as usize],
-            0.80,
-        );
-        colors[Self::TabSelected as usize] = lerp(
-            colors[Self::HeaderActive as usize],
-            colors[Self::TitleBgActive as usize],
-            0.60,
-        );
-        colors[Self::TabSelectedOverline as usize] = colors[Self::HeaderActive as usize];
-        colors[Self::TabDimmed as usize] = lerp(
-            colors[Self::Tab as usize],
-            colors[Self::TitleBg as usize],
-            0.80,
-        );
-        colors[Self::TabDimmedSelected as usize] = lerp(
-            colors[Self::TabSelected as usize],
-            colors[Self::TitleBg as usize],
-            0.40,
+        let mut style = Style::default();
+        style[StyleColor::Button] = [0.5, 0.25, 1.0, 0.4];
+        style.convert_colors_srgb_to_linear();
+        let [r, g, b, a] = style[StyleColor::Button];
+        assert!((r - 0.214).abs() < 1e-3 && (g - 0.0508).abs() < 1e-3);
+        assert!((b - 1.0).abs() < 1e-6);
+        assert_eq!(a, 0.4);
+
+        style.convert_colors_linear_to_srgb();
+        let back = style[StyleColor::Button];
+        for (x, y) in back.iter().zip([0.5, 0.25, 1.0, 0.4]) {
+            assert!((x - y).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_to_commented_toml() {
+        let toml = Style::default().to_commented_toml();
+        let lines: Vec<_> = toml.lines().collect();
+        let i = lines
+            .iter()
+            .position(|l| l.starts_with("window_rounding = "))
+            .unwrap();
+        assert_eq!(lines[i - 1], "# Rounding radius of window corners");
+        assert!(lines.contains(&"alpha = 1.0"));
+        assert!(lines.contains(&"window_menu_button_position = \"Left\""));
+        assert!(lines.contains(&"[colors]"));
+        assert!(lines.iter().any(|l| l.starts_with("WindowBg = [")));
+    }
+
+    #[test]
+    fn test_separator_line() {
+        let style = Style {
+            separator_text_border_size: 4.0,
+            ..Style::default()
+        };
+        let color = style[StyleColor::Separator];
+        assert_eq!(style.separator_line(true), (4.0, color));
+        assert_eq!(style.separator_line(false), (1.0, color));
+    }
+
+    #[test]
+    fn test_theme_transition_start_diff() {
+        let base = Style::default();
+        let mut edited = Style {
+            frame_rounding: 8.0,
+            anti_aliased_fill: !base.anti_aliased_fill,
+            ..base
+        };
+        edited[StyleColor::Button] = [1.0, 0.0, 0.0, 1.0];
+        let diff = edited.diff(&base);
+
+        let mut transition = ThemeTransition::start_diff(&base, &diff, 1.0);
+        let mid = transition.update(0.5);
+        assert!(!transition.is_finished());
+        assert_eq!(mid.frame_rounding, (base.frame_rounding + 8.0) / 2.0);
+        assert_eq!(mid.anti_aliased_fill, base.anti_aliased_fill);
+        let mid_diff = mid.diff(&base);
+        assert!(mid_diff
+            .entries()
+            .iter()
+            .all(|entry| diff.entries().iter().any(|e| e.key == entry.key)));
+
+        let end = transition.update(0.5);
+        assert!(transition.is_finished());
+        assert!(end.diff(&edited).is_empty());
+    }
+
+    #[test]
+    fn test_column_min_width() {
+        let style = Style {
+            columns_min_spacing: 6.0,
+            ..Style::default()
+        };
+        assert_eq!(style.column_min_width(2.0), 6.0);
+        assert_eq!(style.column_min_width(40.0), 40.0);
+    }
+
+    #[test]
+    fn test_color_display_order() {
+        let names: Vec<_> = StyleColor::display_order(ColorSort::Alphabetical)
+            .iter()
+            .map(|c| c.name())
+            .collect();
+        assert!(names.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(names.len(), StyleColor::COUNT);
+
+        assert_eq!(
+            StyleColor::display_order(ColorSort::EnumOrder),
+            StyleColor::VARIANTS
         );
-        colors[Self::TabDimmedSelectedOverline as usize] = colors[Self::HeaderActive as usize];
-        colors[Self::PlotLines as usize] = [1.00, 1.00, 1.00, 1.00];
-        colors[Self::PlotLinesHovered as usize] = [0.90, 0.70, 0.00, 1.00];
-        colors[Self::PlotHistogram as usize] = [0.90, 0.70, 0.00, 1.00];
-        colors[Self::PlotHistogramHovered as usize] = [1.00, 0.60, 0.00, 1.00];
-        colors[Self::TableHeaderBg as usize] = [0.27, 0.27, 0.38, 1.00];
-        colors[Self::TableBorderStrong as usize] = [0.31, 0.31, 0.45, 1.00];
-        colors[Self::TableBorderLight as usize] = [0.26, 0.26, 0.28, 1.00];
-        colors[Self::TableRowBg as usize] = [0.00, 0.00, 0.00, 0.00];
-        colors[Self::TableRowBgAlt as usize] = [1.00, 1.00, 1.00, 0.07];
-        colors[Self::TextLink as usize] = colors[Self::HeaderActive as usize];
-        colors[Self::TextSelectedBg as usize] = [0.00, 0.00, 1.00, 0.35];
-        colors[Self::DragDropTarget as usize] = [1.00, 1.00, 0.00, 0.90];
-        colors[Self::NavCursor as usize] = colors[Self::HeaderHovered as usize];
-        colors[Self::NavWindowingHighlight as usize] = [1.00, 1.00, 1.00, 0.70];
-        colors[Self::NavWindowingDimBg as usize] = [0.80, 0.80, 0.80, 0.20];
-        colors[Self::ModalWindowDimBg as usize] = [0.20, 0.20, 0.20, 0.35];
+        let grouped = StyleColor::display_order(ColorSort::Grouped);
+        assert_eq!(grouped.len(), StyleColor::COUNT);
+        assert!(grouped
+            .windows(2)
+            .all(|w| w[0].category() <= w[1].category()));
+        assert_eq!(&grouped[..2], [StyleColor::Text, StyleColor::TextDisabled]);
+    }
 
-        #[cfg(feature = "docking")]
-        {
-            colors[Self::DockingPreview as usize] = std::array::from_fn(|idx| {
-                let multiplier = if idx == 3 { 0.7 } else { 1.0 };
+    #[test]
+    #[cfg(feature = "docking")]
+    fn test_docking_separator_rect() {
+        let style = Style {
+            docking_separator_size: 4.0,
+            ..Style::default()
+        };
+        let (min, max) = style.docking_separator_rect([10.0, 20.0], 100.0, true);
+        assert_eq!((min, max), ([10.0, 18.0], [110.0, 22.0]));
+        assert_eq!(max[1] - min[1], style.docking_separator_size);
 
-                colors[Self::Header as usize][idx] * multiplier
-            });
+        let (min, max) = style.docking_separator_rect([10.0, 20.0], 50.0, false);
+        assert_eq!((min, max), ([8.0, 20.0], [12.0, 70.0]));
+        assert_eq!(max[0] - min[0], style.docking_separator_size);
+    }
 
-            colors[Self::DockingEmptyBg as usize] = [0.2, 0.2, 0.2, 1.0];
+    #[test]
+    fn test_window_bg_effective() {
+        let mut style = Style {
+            alpha: 0.5,
+            ..Style::default()
+        };
+        style[StyleColor::WindowBg] = [0.1, 0.2, 0.3, 0.8];
+        assert_eq!(style.window_bg_effective(), [0.1, 0.2, 0.3, 0.4]);
+    }
+
+    #[test]
+    fn test_preset_palettes_fill_every_color() {
+        // colors which are fully transparent black on purpose
+        let transparent = [
+            StyleColor::ChildBg,
+            StyleColor::BorderShadow,
+            StyleColor::TableRowBg,
+        ];
+        let presets = [
+            StyleColor::photoshop_colors(),
+            StyleColor::cherry_colors(),
+            StyleColor::dracula_colors(),
+        ];
+        for colors in presets {
+            for (color, value) in StyleColor::VARIANTS.into_iter().zip(colors) {
+                assert!(
+                    value != [0.0; 4] || transparent.contains(&color),
+                    "{} is unset",
+                    color
+                );
+            }
         }
 
-        colors
+        let mut style = Style::default();
+        style.use_dracula_colors();
+        assert_eq!(style.colors, StyleColor::dracula_colors());
     }
-}
 
-impl fmt::Display for StyleColor {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.pad(self.name())
+    #[test]
+    fn test_check_mark_rect() {
+        let style = Style::default();
+        let (min, max) = style.check_mark_rect([10.0, 20.0], 19.0);
+        assert_eq!((min, max), ([13.0, 23.0], [26.0, 36.0]));
+        // inset by the same amount on every side
+        assert_eq!(min[0] - 10.0, 10.0 + 19.0 - max[0]);
+        assert_eq!(min[1] - 20.0, 20.0 + 19.0 - max[1]);
+
+        let (min, max) = style.check_mark_rect([0.0, 0.0], 4.0);
+        assert_eq!((min, max), ([1.0, 1.0], [3.0, 3.0]));
     }
-}
 
-impl TryFrom<usize> for StyleColor {
-    type Error = InvalidStyleColorValue;
+    #[test]
+    fn test_contrast_ratio() {
+        let black = [0.0, 0.0, 0.0, 1.0];
+        let white = [1.0, 1.0, 1.0, 1.0];
+        assert!((contrast_ratio(black, white) - 21.0).abs() < 1e-4);
+        assert!((contrast_ratio(white, black) - 21.0).abs() < 1e-4);
+        assert_eq!(contrast_ratio(white, white), 1.0);
+        // fully transparent text disappears into the background
+        assert_eq!(contrast_ratio([0.0, 0.0, 0.0, 0.0], white), 1.0);
 
-    fn try_from(value: usize) -> Result<Self, Self::Error> {
-        if value >= StyleColor::COUNT {
-            Err(InvalidStyleColorValue)
-        } else {
-            Ok(Self::VARIANTS[value])
+        let mut style = Style::default();
+        style[StyleColor::Text] = [1.0, 1.0, 1.0, 1.0];
+        style[StyleColor::WindowBg] = [0.0, 0.0, 0.0, 1.0];
+        style[StyleColor::Button] = [0.9, 0.9, 0.9, 1.0];
+        let low = style.check_text_contrast();
+        assert!(low.iter().any(|&(c, _)| c == StyleColor::Button));
+        assert!(low
+            .iter()
+            .all(|&(c, r)| c != StyleColor::WindowBg && r < 4.5));
+    }
+
+    #[test]
+    fn test_style_builder() {
+        let style = StyleBuilder::from_style(Style {
+            alpha: 0.5,
+            ..Style::default()
+        })
+        .colors(StyleColor::light_colors())
+        .window_rounding(6.0)
+        .anti_aliased_fill(false)
+        .window_menu_button_position(Direction::Right)
+        .color(StyleColor::Button, [1.0, 0.0, 0.0, 1.0])
+        .build();
+
+        let mut expected = Style {
+            alpha: 0.5,
+            window_rounding: 6.0,
+            anti_aliased_fill: false,
+            window_menu_button_position: Direction::Right,
+            colors: StyleColor::light_colors(),
+            ..Style::default()
+        };
+        expected[StyleColor::Button] = [1.0, 0.0, 0.0, 1.0];
+        assert_eq!(style, expected);
+    }
+
+    #[test]
+    fn test_dump_fields() {
+        let style = Style::default();
+        let fields = style.dump_fields();
+        let field_count = if cfg!(feature = "docking") { 56 } else { 55 };
+        assert_eq!(fields.len(), field_count + StyleColor::COUNT);
+        assert_eq!(fields[0], ("alpha", StyleFieldValue::F32(style.alpha)));
+        assert_eq!(
+            fields
+                .iter()
+                .any(|&(name, _)| name == "docking_separator_size"),
+            cfg!(feature = "docking")
+        );
+        assert_eq!(
+            fields[field_count],
+            ("Text", StyleFieldValue::Color(style[StyleColor::Text]))
+        );
+    }
+
+    #[test]
+    fn test_nested_surface_color() {
+        let mut style = Style::default();
+        style[StyleColor::WindowBg] = [0.0, 0.0, 0.0, 0.5];
+        style[StyleColor::ChildBg] = [1.0, 1.0, 1.0, 0.5];
+        assert_eq!(style.nested_surface_color(0), style[StyleColor::WindowBg]);
+
+        let mut previous = style.nested_surface_color(0);
+        for depth in 1..5 {
+            let color = style.nested_surface_color(depth);
+            assert!(color[3] > previous[3] && color[0] > previous[0]);
+            assert!(color[3] <= 1.0 && color[0] <= 1.0);
+            previous = color;
         }
     }
-}
 
-impl TryFrom<u32> for StyleColor {
-    type Error = InvalidStyleColorValue;
+    #[test]
+    fn test_colors_packed() {
+        assert_eq!(color_to_u32([1.0, 0.0, 0.0, 1.0]), 0xFF0000FF);
+        assert_eq!(color_to_u32([2.0, -1.0, 0.5, 0.0]), 0x008000FF);
+        assert_eq!(color_from_u32(0xFF00FF00), [0.0, 1.0, 0.0, 1.0]);
 
-    fn try_from(value: u32) -> Result<Self, Self::Error> {
-        Self::try_from(value as usize)
+        let style = Style::default();
+        let mut copy = Style {
+            colors: [[0.0; 4]; StyleColor::COUNT],
+            ..Style::default()
+        };
+        copy.set_colors_packed(&style.colors_packed());
+        for (a, b) in style.colors.iter().zip(copy.colors.iter()) {
+            for (x, y) in a.iter().zip(b) {
+                assert!((x - y).abs() <= 0.5 / 255.0 + 1e-6);
+            }
+        }
     }
-}
 
-#[derive(Debug)]
-pub struct InvalidStyleColorValue;
-impl fmt::Display for InvalidStyleColorValue {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.pad("Invalid style color value -- must be between 0..Self::COUNT")
+    #[test]
+    fn test_to_cpp_source() {
+        let base = Style::default();
+        assert_eq!(
+            base.to_cpp_source(&base),
+            "ImGuiStyle& style = ImGui::GetStyle();\n"
+        );
+
+        let mut style = base;
+        style.window_padding = [4.0, 2.5];
+        style.window_menu_button_position = Direction::Right;
+        style.anti_aliased_fill = false;
+        style.tab_min_width_for_close_button = 10.0;
+        style.circle_tesselation_max_error = 0.5;
+        style[StyleColor::Button] = [1.0, 0.5, 0.25, 1.0];
+        let cpp = style.to_cpp_source(&base);
+        let lines: Vec<_> = cpp.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "ImGuiStyle& style = ImGui::GetStyle();",
+                "style.WindowPadding = ImVec2(4.0f, 2.5f);",
+                "style.WindowMenuButtonPosition = ImGuiDir_Right;",
+                "style.TabCloseButtonMinWidthUnselected = 10.0f;",
+                "style.AntiAliasedFill = false;",
+                "style.CircleTessellationMaxError = 0.5f;",
+                "style.Colors[ImGuiCol_Button] = ImVec4(1.0f, 0.5f, 0.25f, 1.0f);",
+            ]
+        );
     }
-}
-impl std::error::Error for InvalidStyleColorValue {}
 
-/// A temporary change in user interface style
-#[derive(Copy, Clone, Debug, PartialEq)]
-#[non_exhaustive]
-pub enum StyleVar {
-    /// Global alpha applies to everything
-    Alpha(f32),
-    /// Padding within a window
-    WindowPadding([f32; 2]),
-    /// Rounding radius of window corners
-    WindowRounding(f32),
-    /// Thickness of border around windows
-    WindowBorderSize(f32),
-    /// Minimum window size
-    WindowMinSize([f32; 2]),
-    /// Alignment for title bar text
-    WindowTitleAlign([f32; 2]),
-    /// Rounding radius of child window corners
-    ChildRounding(f32),
-    /// Thickness of border around child windows
-    ChildBorderSize(f32),
-    /// Rounding radius of popup window corners
-    PopupRounding(f32),
-    /// Thickness of border around popup/tooltip windows
-    PopupBorderSize(f32),
-    /// Padding within a framed rectangle (used by most widgets)
-    FramePadding([f32; 2]),
-    /// Rounding radius of frame corners (used by most widgets)
-    FrameRounding(f32),
-    /// Thickness of border around frames
-    FrameBorderSize(f32),
-    /// Horizontal and vertical spacing between widgets/lines
-    ItemSpacing([f32; 2]),
-    /// Horizontal and vertical spacing between elements of a composed widget (e.g. a slider and
-    /// its label)
-    ItemInnerSpacing([f32; 2]),
-    /// Horizontal indentation when e.g. entering a tree node
-    IndentSpacing(f32),
-    /// Width of the vertical scrollbar, height of the horizontal scrollbar
-    ScrollbarSize(f32),
-    /// Rounding radius of scrollbar grab corners
-    ScrollbarRounding(f32),
-    /// Minimum width/height of a grab box for slider/scrollbar
-    GrabMinSize(f32),
-    /// Rounding radius of grab corners
-    GrabRounding(f32),
-    /// Rounding radius of upper corners of tabs
-    TabRounding(f32),
-    /// Alignment of button text when button is larger than text
-    ButtonTextAlign([f32; 2]),
-    /// Alignment of selectable text when selectable is larger than text
-    SelectableTextAlign([f32; 2]),
-    /// Padding within a table cell
-    CellPadding([f32; 2]),
-}
+    #[test]
+    fn test_combo_arrow_width() {
+        let style = Style {
+            frame_padding: [4.0, 3.0],
+            ..Style::default()
+        };
+        assert_eq!(style.combo_arrow_width(13.0), 13.0 + 2.0 * 3.0);
+        assert_eq!(style.combo_arrow_width(13.0), style.frame_height(13.0));
+    }
 
-// lerps a color with the given value
-fn lerp(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
-    std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
-}
+    #[test]
+    fn test_ini_roundtrip() {
+        let base = Style::default();
+        let ini = base.write_ini();
+        assert!(ini.starts_with("[Style]\n"));
+        assert!(ini.contains("\nColors.WindowBg=0.06,0.06,0.06,0.94\n"));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let mut style = Style {
+            frame_rounding: 7.0,
+            window_menu_button_position: Direction::Right,
+            colors: StyleColor::light_colors(),
+            ..base
+        };
+        style.load_ini(&ini).unwrap();
+        assert_eq!(style, base);
+
+        let mut style = base;
+        style
+            .load_ini("; comment\n\n[Style]\nframe_rounding = 4\nunknown=1\nColors.Unknown=1\n")
+            .unwrap();
+        assert_eq!(style.frame_rounding, 4.0);
+    }
 
     #[test]
-    fn test_style_scaling() {
-        let (_guard, ctx) = crate::test::test_ctx();
-        let mut style = *ctx.style();
-        style.window_padding = [1.0, 2.0];
-        style.window_rounding = 3.0;
-        style.window_min_size = [4.0, 5.0];
-        style.child_rounding = 6.0;
-        style.popup_rounding = 7.0;
-        style.frame_padding = [8.0, 9.0];
-        style.frame_rounding = 10.0;
-        style.item_spacing = [11.0, 12.0];
-        style.item_inner_spacing = [13.0, 14.0];
-        style.touch_extra_padding = [15.0, 16.0];
-        style.indent_spacing = 17.0;
-        style.columns_min_spacing = 18.0;
-        style.scrollbar_size = 19.0;
-        style.scrollbar_rounding = 20.0;
-        style.grab_min_size = 21.0;
-        style.grab_rounding = 22.0;
-        style.log_slider_deadzone = 29.0;
-        style.tab_rounding = 23.0;
-        style.display_window_padding = [24.0, 25.0];
-        style.display_safe_area_padding = [26.0, 27.0];
-        style.mouse_cursor_scale = 28.0;
-        style.cell_padding = [29.0, 30.0];
-        style.scale_all_sizes(2.0);
-        assert_eq!(style.window_padding, [2.0, 4.0]);
-        assert_eq!(style.window_rounding, 6.0);
-        assert_eq!(style.window_min_size, [8.0, 10.0]);
-        assert_eq!(style.child_rounding, 12.0);
-        assert_eq!(style.popup_rounding, 14.0);
-        assert_eq!(style.frame_padding, [16.0, 18.0]);
-        assert_eq!(style.frame_rounding, 20.0);
-        assert_eq!(style.item_spacing, [22.0, 24.0]);
-        assert_eq!(style.item_inner_spacing, [26.0, 28.0]);
-        assert_eq!(style.touch_extra_padding, [30.0, 32.0]);
-        assert_eq!(style.indent_spacing, 34.0);
-        assert_eq!(style.columns_min_spacing, 36.0);
-        assert_eq!(style.scrollbar_size, 38.0);
-        assert_eq!(style.scrollbar_rounding, 40.0);
-        assert_eq!(style.grab_min_size, 42.0);
-        assert_eq!(style.grab_rounding, 44.0);
-        assert_eq!(style.log_slider_deadzone, 58.0);
-        assert_eq!(style.tab_rounding, 46.0);
-        assert_eq!(style.display_window_padding, [48.0, 50.0]);
-        assert_eq!(style.display_safe_area_padding, [52.0, 54.0]);
-        assert_eq!(style.mouse_cursor_scale, 56.0);
-        assert_eq!(style.cell_padding, [58.0, 60.0]);
+    fn test_load_ini_errors() {
+        let mut style = Style::default();
+        assert_eq!(
+            style.load_ini("[Style]\nframe_rounding=4\nwindow_padding"),
+            Err(StyleIniError::MissingEquals { line: 3 })
+        );
+        assert_eq!(
+            style.load_ini("Colors.Text=1,1,1"),
+            Err(StyleIniError::InvalidValue {
+                line: 1,
+                key: "Colors.Text".into(),
+                value: "1,1,1".into(),
+            })
+        );
+        assert_eq!(style, Style::default());
     }
 
     #[test]
-    fn test_style_color_indexing() {
-        let (_guard, ctx) = crate::test::test_ctx();
-        let mut style = *ctx.style();
-        let value = [0.1, 0.2, 0.3, 1.0];
-        style[StyleColor::Tab] = value;
-        assert_eq!(style[StyleColor::Tab], value);
-        assert_eq!(style.colors[StyleColor::Tab as usize], value);
+    fn test_resize_edge_hit_width() {
+        let style = Style::default();
+        assert_eq!(style.resize_edge_hit_width(), 8.0);
+        assert!(style.resize_edge_hit_width() > style.window_border_size);
+
+        let style = Style {
+            touch_extra_padding: [3.0, 10.0],
+            ..Style::default()
+        };
+        assert_eq!(style.resize_edge_hit_width(), 20.0);
+
+        let style = Style {
+            window_border_hover_padding: 6.0,
+            ..Style::default()
+        };
+        assert_eq!(style.resize_edge_hit_width(), 12.0);
+    }
+
+    #[test]
+    fn test_plot_colormap() {
+        let style = Style::default();
+        let ramp = style.plot_colormap(ColormapKind::Theme, 5);
+        assert_eq!(ramp.len(), 5);
+        assert_eq!(ramp[0], style[StyleColor::PlotHistogram]);
+        assert_eq!(ramp[4], style[StyleColor::PlotHistogramHovered]);
+        assert_eq!(
+            ramp[2],
+            lerp(
+                style[StyleColor::PlotHistogram],
+                style[StyleColor::PlotHistogramHovered],
+                0.5
+            )
+        );
+
+        let viridis = style.plot_colormap(ColormapKind::Viridis, 9);
+        assert_eq!(viridis[0], [0.267, 0.005, 0.329, 1.0]);
+        assert_eq!(viridis[8], [0.992, 0.906, 0.145, 1.0]);
+        assert_eq!(viridis[4], [0.129, 0.569, 0.549, 1.0]);
+
+        assert!(style.plot_colormap(ColormapKind::Theme, 0).is_empty());
+        assert_eq!(
+            style.plot_colormap(ColormapKind::Viridis, 1),
+            [[0.267, 0.005, 0.329, 1.0]]
+        );
+    }
+
+    #[test]
+    fn test_to_ffi_safe() {
+        assert_eq!(Style::default().to_ffi_safe(), Style::default());
+
+        let mut style = Style {
+            alpha: f32::NAN,
+            disabled_alpha: 3.0,
+            window_padding: [f32::INFINITY, -4.0],
+            window_min_size: [0.0, 32.0],
+            button_text_align: [-1.0, 2.0],
+            curve_tessellation_tol: 0.0,
+            table_angled_headers_angle: -3.0,
+            window_menu_button_position: Direction::Up,
+            color_button_position: Direction::None,
+            ..Style::default()
+        };
+        style[StyleColor::Text] = [f32::NAN, 2.0, -1.0, 0.5];
+        let safe = style.to_ffi_safe();
+        assert_eq!(safe.alpha, Style::default().alpha);
+        assert_eq!(safe.disabled_alpha, 1.0);
+        assert_eq!(
+            safe.window_padding,
+            [Style::default().window_padding[0], 0.0]
+        );
+        assert_eq!(safe.window_min_size, [1.0, 32.0]);
+        assert_eq!(safe.button_text_align, [0.0, 1.0]);
+        assert_eq!(safe.curve_tessellation_tol, 0.1);
+        assert_eq!(safe.table_angled_headers_angle, -50f32.to_radians());
+        assert_eq!(safe.window_menu_button_position, Direction::Left);
+        assert_eq!(safe.color_button_position, Direction::Right);
+        assert_eq!(
+            safe[StyleColor::Text],
+            [Style::default()[StyleColor::Text][0], 1.0, 0.0, 0.5]
+        );
+        assert!(safe.field_values().iter().all(|(_, v)| match v {
+            StyleFieldValue::F32(v) => v.is_finite(),
+            StyleFieldValue::Vec2(v) => v.iter().all(|v| v.is_finite()),
+            _ => true,
+        }));
     }
 
     #[test]
@@ -1057,6 +5311,7 @@ mod tests {
         assert_field_offset!(window_padding, WindowPadding);
         assert_field_offset!(window_rounding, WindowRounding);
         assert_field_offset!(window_border_size, WindowBorderSize);
+        assert_field_offset!(window_border_hover_padding, WindowBorderHoverPadding);
         assert_field_offset!(window_min_size, WindowMinSize);
         assert_field_offset!(window_title_align, WindowTitleAlign);
         assert_field_offset!(window_menu_button_position, WindowMenuButtonPosition);
@@ -1078,9 +5333,17 @@ mod tests {
         assert_field_offset!(grab_min_size, GrabMinSize);
         assert_field_offset!(grab_rounding, GrabRounding);
         assert_field_offset!(log_slider_deadzone, LogSliderDeadzone);
+        assert_field_offset!(image_border_size, ImageBorderSize);
         assert_field_offset!(tab_rounding, TabRounding);
         assert_field_offset!(tab_border_size, TabBorderSize);
-        assert_field_offset!(tab_min_width_for_close_button, TabMinWidthForCloseButton);
+        assert_field_offset!(
+            tab_close_button_min_width_selected,
+            TabCloseButtonMinWidthSelected
+        );
+        assert_field_offset!(
+            tab_min_width_for_close_button,
+            TabCloseButtonMinWidthUnselected
+        );
         assert_field_offset!(color_button_position, ColorButtonPosition);
         assert_field_offset!(button_text_align, ButtonTextAlign);
         assert_field_offset!(selectable_text_align, SelectableTextAlign);
@@ -2,9 +2,10 @@
 
 use bitflags::bitflags;
 
+use crate::draw_list::DrawFlags;
 use crate::input::mouse::MouseButton;
 use crate::math::MintVec2;
-use crate::style::StyleColor;
+use crate::style::{StatusColors, StatusKind, StyleColor};
 use crate::sys;
 use crate::Style;
 use crate::Ui;
@@ -236,6 +237,31 @@ impl Ui {
         unsafe { self.style() }.colors[style_color as usize]
     }
 
+    /// Returns the rounding and corner flags for drawing custom window backgrounds, like
+    /// [`Style::window_draw_flags`], but rounding only the corners in
+    /// [`StyleExtras::window_corner_mask`](crate::StyleExtras::window_corner_mask).
+    pub fn window_draw_flags(&self) -> (f32, DrawFlags) {
+        let (rounding, flags) = unsafe { self.style() }.window_draw_flags();
+        let mask = self.style_extras().window_corner_mask & DrawFlags::ROUND_CORNERS_ALL;
+        if flags == DrawFlags::ROUND_CORNERS_NONE || mask.is_empty() {
+            (0.0, DrawFlags::ROUND_CORNERS_NONE)
+        } else {
+            (rounding, mask)
+        }
+    }
+
+    /// Returns the color for a semantic status.
+    ///
+    /// This uses [`StyleExtras::status_colors`](crate::StyleExtras::status_colors) if set,
+    /// and otherwise derives the color from the active palette.
+    pub fn status_color(&self, kind: StatusKind) -> [f32; 4] {
+        let colors = self.style_extras().status_colors.unwrap_or_else(|| {
+            let accent = unsafe { *sys::igGetStyleColorVec4(StyleColor::CheckMark as i32) };
+            StatusColors::from_accent([accent.x, accent.y, accent.z, accent.w])
+        });
+        colors.get(kind)
+    }
+
     /// Gets the name of some style color.
     ///
     /// This is just a wrapper around calling [`name`] on [StyleColor].
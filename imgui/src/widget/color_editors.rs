@@ -5,7 +5,7 @@ use crate::math::MintVec2;
 use crate::math::MintVec3;
 use crate::math::MintVec4;
 use crate::sys;
-use crate::Ui;
+use crate::{Style, StyleColor, Ui};
 
 // /// Mutable reference to an editable color value.
 // #[derive(Debug)]
@@ -609,6 +609,15 @@ impl Ui {
             ui: self,
         }
     }
+
+    /// Edits a single color of `style` in place, labeled with [`StyleColor::name`], for
+    /// building theme editors. Returns true if the color was changed.
+    ///
+    /// The editor's id is scoped by the color, so editors for several colors never collide.
+    pub fn style_color_edit(&self, style: &mut Style, color: StyleColor) -> bool {
+        let _id = self.push_id_int(color as i32);
+        self.color_edit4(color.name(), &mut style[color])
+    }
 }
 
 /// Builder for a color picker widget.
@@ -1231,3 +1240,18 @@ impl Ui {
         }
     }
 }
+
+#[test]
+fn test_style_color_edit() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut style = Style::default();
+    let ui = ctx.new_frame();
+    ui.window("theme").build(|| {
+        let id = || unsafe { sys::igGetID_Str(c"probe".as_ptr()) };
+        let before = id();
+        assert!(!ui.style_color_edit(&mut style, StyleColor::Button));
+        assert!(!ui.style_color_edit(&mut style, StyleColor::ButtonActive));
+        assert_eq!(id(), before);
+    });
+    assert_eq!(style, Style::default());
+}
@@ -3,7 +3,7 @@ use std::ops::{BitAnd, BitAndAssign, BitOrAssign, Not};
 
 use crate::math::MintVec2;
 use crate::sys;
-use crate::{Direction, Ui};
+use crate::{Direction, StyleColor, Ui};
 
 bitflags!(
     /// Flags for invisible buttons
@@ -74,6 +74,104 @@ impl Ui {
             )
         }
     }
+    /// Renders a clickable button filled with a vertical gradient.
+    ///
+    /// The colors come from [`StyleExtras::button_gradient`](crate::StyleExtras::button_gradient),
+    /// falling back to the `Button` and `ButtonActive` style colors. The gradient is lightened
+    /// toward `ButtonHovered` while the button is hovered, and flipped while it is held down.
+    /// Like other buttons, the frame follows `frame_rounding` and `frame_border_size`, the label
+    /// is placed with `button_text_align`, every color is faded by the style `alpha` (including
+    /// while disabled), and text after `##` in the label is hidden.
+    ///
+    /// Returns true if this button was clicked.
+    ///
+    /// Setting `size` as `[0.0, 0.0]` will size the button to the label's width in
+    /// the current style.
+    pub fn gradient_button(&self, label: impl AsRef<str>, size: impl Into<MintVec2>) -> bool {
+        let label = label.as_ref();
+        let size: [f32; 2] = size.into().into();
+        let text_size = self.calc_text_size_with_opts(label, true, -1.0);
+        let (padding, rounding, border_size, text_align, alpha) = unsafe {
+            let style = &*sys::igGetStyle();
+            (
+                style.FramePadding,
+                style.FrameRounding,
+                style.FrameBorderSize,
+                style.ButtonTextAlign,
+                style.Alpha,
+            )
+        };
+        let size = [
+            if size[0] > 0.0 {
+                size[0]
+            } else {
+                text_size[0] + padding.x * 2.0
+            },
+            if size[1] > 0.0 {
+                size[1]
+            } else {
+                text_size[1] + padding.y * 2.0
+            },
+        ];
+
+        let faded = |c: [f32; 4]| [c[0], c[1], c[2], c[3] * alpha];
+        let color = |c: StyleColor| {
+            let v = unsafe { *sys::igGetStyleColorVec4(c as i32) };
+            faded([v.x, v.y, v.z, v.w])
+        };
+        let (top, bottom) = self
+            .style_extras()
+            .button_gradient
+            .map(|(top, bottom)| (faded(top), faded(bottom)))
+            .unwrap_or_else(|| (color(StyleColor::Button), color(StyleColor::ButtonActive)));
+
+        let clicked = self.invisible_button(label, size);
+        let (top, bottom) = if self.is_item_active() {
+            (bottom, top)
+        } else if self.is_item_hovered() {
+            let hovered = color(StyleColor::ButtonHovered);
+            let lighten = |c: [f32; 4]| -> [f32; 4] {
+                std::array::from_fn(|i| c[i] + (hovered[i] - c[i]) * 0.5)
+            };
+            (lighten(top), lighten(bottom))
+        } else {
+            (top, bottom)
+        };
+        let min = self.item_rect_min();
+        let max = self.item_rect_max();
+        // same placement as RenderTextClipped: aligned inside the padding, never left of it
+        let text_pos = [
+            min[0] + padding.x + (size[0] - padding.x * 2.0 - text_size[0]).max(0.0) * text_align.x,
+            min[1] + padding.y + (size[1] - padding.y * 2.0 - text_size[1]).max(0.0) * text_align.y,
+        ];
+        let draw_list = self.get_window_draw_list();
+        let start = draw_list.vtx_count();
+        draw_list
+            .add_rect(min, max, [1.0, 1.0, 1.0, 1.0])
+            .rounding(rounding)
+            .filled(true)
+            .build();
+        draw_list.shade_verts_vertical_gradient(start, min[1], max[1], top, bottom);
+        if border_size > 0.0 {
+            draw_list
+                .add_rect(
+                    [min[0] + 1.0, min[1] + 1.0],
+                    [max[0] + 1.0, max[1] + 1.0],
+                    color(StyleColor::BorderShadow),
+                )
+                .rounding(rounding)
+                .thickness(border_size)
+                .build();
+            draw_list
+                .add_rect(min, max, color(StyleColor::Border))
+                .rounding(rounding)
+                .thickness(border_size)
+                .build();
+        }
+        let visible_label = label.find("##").map_or(label, |end| &label[..end]);
+        draw_list.add_text(text_pos, color(StyleColor::Text), visible_label);
+        clicked
+    }
     /// Renders a square button with an arrow shape.
     ///
     /// Returns true if this button was clicked.
@@ -133,3 +231,89 @@ impl Ui {
         unsafe { sys::igBullet() };
     }
 }
+
+#[test]
+fn test_gradient_button_click() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.style_extras_mut().button_gradient = Some(([1.0, 0.0, 0.0, 1.0], [0.0, 0.0, 1.0, 1.0]));
+
+    let mut frame = |pos: [f32; 2], down: bool| {
+        ctx.io_mut().add_mouse_pos_event(pos);
+        ctx.io_mut()
+            .add_mouse_button_event(crate::MouseButton::Left, down);
+        let ui = ctx.new_frame();
+        let clicked = ui
+            .window("gradient")
+            .position([0.0, 0.0], crate::Condition::Always)
+            .size([200.0, 100.0], crate::Condition::Always)
+            .build(|| {
+                ui.set_cursor_screen_pos([20.0, 40.0]);
+                ui.gradient_button("Go", [80.0, 30.0])
+            });
+        let _ = ctx.render();
+        clicked.unwrap()
+    };
+
+    assert!(!frame([50.0, 50.0], false));
+    assert!(!frame([50.0, 50.0], true));
+    assert!(frame([50.0, 50.0], false));
+}
+
+#[test]
+fn test_gradient_button_hides_id_suffix() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.window("gradient").build(|| {
+        let padding = unsafe { (*sys::igGetStyle()).FramePadding };
+        let text_size = ui.calc_text_size("Go");
+        ui.gradient_button("Go##row3", [0.0, 0.0]);
+        assert_eq!(
+            ui.item_rect_size(),
+            [
+                text_size[0] + padding.x * 2.0,
+                text_size[1] + padding.y * 2.0
+            ]
+        );
+        let first = unsafe { sys::igGetItemID() };
+        ui.gradient_button("Go##row4", [0.0, 0.0]);
+        assert_ne!(unsafe { sys::igGetItemID() }, first);
+    });
+}
+
+#[test]
+fn test_gradient_button_alpha_and_text_align() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.style_extras_mut().button_gradient = Some(([1.0, 0.0, 1.0, 1.0], [1.0, 0.0, 1.0, 1.0]));
+    ctx.style_mut().button_text_align = [0.0, 0.5];
+    let padding = ctx.style().frame_padding;
+
+    let ui = ctx.new_frame();
+    ui.window("gradient")
+        .position([0.0, 0.0], crate::Condition::Always)
+        .size([300.0, 100.0], crate::Condition::Always)
+        .no_decoration()
+        .build(|| {
+            ui.set_cursor_screen_pos([20.0, 40.0]);
+            let _alpha = ui.push_style_var(crate::StyleVar::Alpha(0.5));
+            ui.gradient_button("Go", [200.0, 30.0]);
+        });
+    let draw_data = ctx.render();
+    let verts: Vec<_> = draw_data
+        .draw_lists()
+        .flat_map(|list| list.vtx_buffer().iter().copied())
+        .collect();
+
+    let fill: Vec<_> = verts
+        .iter()
+        .filter(|v| v.col[..3] == [255, 0, 255])
+        .collect();
+    assert!(!fill.is_empty());
+    assert!(fill.iter().all(|v| (127..=128).contains(&v.col[3])));
+
+    let text_left = verts
+        .iter()
+        .filter(|v| v.col[..3] == [255, 255, 255] && v.col[3] > 0)
+        .map(|v| v.pos[0])
+        .fold(f32::INFINITY, f32::min);
+    assert!(text_left >= 20.0 + padding[0] && text_left < 20.0 + padding[0] + 4.0);
+}
@@ -1,10 +1,12 @@
 use bitflags::bitflags;
 use std::f32;
+use std::marker::PhantomData;
 use std::ptr;
 
 use crate::math::MintVec2;
+use crate::stacks::PushedStyleToken;
 use crate::sys;
-use crate::{Condition, Ui};
+use crate::{Condition, Id, Ui};
 
 pub(crate) mod child_window;
 pub(crate) mod content_region;
@@ -178,6 +180,7 @@ pub struct Window<'ui, 'a, Label> {
     collapsed_cond: Condition,
     focused: bool,
     bg_alpha: f32,
+    viewport: Option<Id>,
 }
 
 impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
@@ -200,6 +203,7 @@ impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
             collapsed_cond: Condition::Never,
             focused: false,
             bg_alpha: f32::NAN,
+            viewport: None,
         }
     }
     /// Enables the window close button, which sets the passed boolean to false when clicked
@@ -280,6 +284,16 @@ impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
         self.bg_alpha = bg_alpha;
         self
     }
+    /// Submits the window to the given viewport.
+    ///
+    /// While the window is open, the style override registered for the viewport with
+    /// [`Context::set_viewport_style`](crate::Context::set_viewport_style) is pushed, if any.
+    /// With the `docking` feature, the window is also moved into the viewport.
+    #[inline]
+    pub fn viewport(mut self, viewport: Id) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
     /// Enables/disables the title bar.
     ///
     /// Enabled by default.
@@ -523,6 +537,13 @@ impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
         if self.bg_alpha.is_finite() {
             unsafe { sys::igSetNextWindowBgAlpha(self.bg_alpha) };
         }
+        #[cfg(feature = "docking")]
+        if let Some(viewport) = self.viewport {
+            unsafe { sys::igSetNextWindowViewport(viewport.0) };
+        }
+        let viewport_style = self
+            .viewport
+            .and_then(|viewport| self.ui.push_viewport_style(viewport));
         let should_render = unsafe {
             sys::igBegin(
                 self.ui.scratch_txt(self.name),
@@ -533,7 +554,10 @@ impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
             )
         };
         if should_render {
-            Some(WindowToken::new(self.ui))
+            Some(WindowToken {
+                _viewport_style: viewport_style,
+                _ui: PhantomData,
+            })
         } else {
             unsafe { sys::igEnd() };
             None
@@ -549,11 +573,25 @@ impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
     }
 }
 
-create_token!(
-    /// Tracks a window that can be ended by calling `.end()`
-    /// or by dropping.
-    pub struct WindowToken<'ui>;
+/// Tracks a window that can be ended by calling `.end()`
+/// or by dropping.
+#[must_use]
+pub struct WindowToken<'ui> {
+    // popped after the window has ended, as fields drop after `Drop::drop`
+    _viewport_style: Option<PushedStyleToken<'ui>>,
+    _ui: PhantomData<&'ui Ui>,
+}
 
+impl WindowToken<'_> {
     /// Ends a window
-    drop { sys::igEnd() }
-);
+    #[inline]
+    pub fn end(self) {
+        // left empty for drop
+    }
+}
+
+impl Drop for WindowToken<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::igEnd() }
+    }
+}